@@ -0,0 +1,75 @@
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+const TRUTHY: [&str; 4] = ["true", "1", "yes", "on"];
+const FALSEY: [&str; 4] = ["false", "0", "no", "off"];
+
+impl Validator {
+    /// Sets the tokens coerced to `true`, replacing `true`, `1`, `yes` and `on`.
+    ///
+    /// # Arguments
+    /// * `truthy` - A slice of tokens, e.g. `&["yes", "y", "checked"]` for a legacy client.
+    pub fn set_truthy<T>(mut self, truthy: &[T]) -> Self
+    where T: ToString
+    {
+        self.truthy = Some(truthy.iter().map(|token| token.to_string()).collect::<Vec<String>>());
+        self
+    }
+
+    /// Sets the tokens coerced to `false`, replacing `false`, `0`, `no` and `off`.
+    ///
+    /// # Arguments
+    /// * `falsey` - A slice of tokens.
+    pub fn set_falsey<T>(mut self, falsey: &[T]) -> Self
+    where T: ToString
+    {
+        self.falsey = Some(falsey.iter().map(|token| token.to_string()).collect::<Vec<String>>());
+        self
+    }
+
+    /// Coerces the string value to a boolean using the truthy and falsey tokens.
+    ///
+    /// Surrounding whitespace is ignored, and tokens are compared case-insensitively unless the
+    /// validator is case sensitive. An unknown token reports every accepted token in the `options` arg.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Option<bool>>)` - The boolean, or `None` for an empty optional value, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, or the value is neither truthy nor falsey.
+    pub fn validate_bool(&self) -> Result<Valid<Option<bool>>, Error> {
+        let (outcome, value) = self.parse_bool();
+        self.to_valid(outcome, value)
+    }
+
+    /// Matches the string value against the truthy and falsey tokens.
+    fn parse_bool(&self) -> (Null<String>, Option<bool>) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), None),
+                false => (Null::Undefined, None)
+            };
+        }
+
+        let truthy = self.truthy.clone().unwrap_or_else(|| TRUTHY.map(String::from).to_vec());
+        let falsey = self.falsey.clone().unwrap_or_else(|| FALSEY.map(String::from).to_vec());
+
+        let matches = |tokens: &[String]| tokens.iter().any(|token| match self.is_case_sensitive {
+            true => token == value,
+            false => token.eq_ignore_ascii_case(value)
+        });
+
+        match (matches(&truthy), matches(&falsey)) {
+            (true, _) => (Null::Undefined, Some(true)),
+            (false, true) => (Null::Undefined, Some(false)),
+            (false, false) => {
+                let options = truthy.into_iter().chain(falsey).collect::<Vec<String>>();
+
+                (Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("options", self.format_options(&options))
+                    .build()), None)
+            }
+        }
+    }
+}
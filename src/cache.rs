@@ -0,0 +1,201 @@
+use nulls::Null;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::message::{self, Message};
+
+const MAX_ENTRIES: usize = 10_000;
+
+/// Cache key: (rule fingerprint, value hash).
+type Key = (u64, u64);
+
+/// Cached outcomes keyed by (rule fingerprint, value hash), with their insertion time and the
+/// message behind an error.
+///
+/// Every entry shares one TTL, so insertion order is also expiry order: `order` queues keys as
+/// they are inserted, letting expired and oldest entries be evicted from its front in O(1).
+/// A key re-inserted after expiring leaves a stale record behind, skipped when it reaches the front.
+#[derive(Default)]
+struct Entries {
+    outcomes: HashMap<Key, (Instant, Null<String>, Option<Message>)>,
+    order: VecDeque<(Key, Instant)>
+}
+
+impl Entries {
+    /// Removes the oldest entry, returning whether one was removed.
+    fn evict_oldest(&mut self) -> bool {
+        while let Some((key, created)) = self.order.pop_front() {
+            if self.outcomes.get(&key).is_some_and(|(current, ..)| *current == created) {
+                self.outcomes.remove(&key);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Removes entries older than `ttl` from the front of the queue.
+    fn prune(&mut self, now: Instant, ttl: Duration) {
+        while let Some((key, created)) = self.order.front().copied()
+            && now.duration_since(created) >= ttl
+        {
+            self.order.pop_front();
+
+            if self.outcomes.get(&key).is_some_and(|(current, ..)| *current == created) {
+                self.outcomes.remove(&key);
+            }
+        }
+    }
+}
+
+/// A memoization layer for expensive validations, keyed by (rule fingerprint, value hash).
+///
+/// Clones share the same entries, so one cache can be handed to every request handler.
+/// Entries expire after the configured TTL; expired entries are pruned on insert and, when still
+/// full, the oldest entry is evicted, both in O(1) per removed entry.
+#[derive(Clone)]
+pub struct ValidationCache {
+    pub ttl: Duration,
+    pub max_entries: usize,
+    entries: Arc<Mutex<Entries>>
+}
+
+impl fmt::Debug for ValidationCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationCache")
+            .field("ttl", &self.ttl)
+            .field("max_entries", &self.max_entries)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Hashes any value into a 64-bit cache key component.
+fn hash<T>(value: T) -> u64
+where T: Hash
+{
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl ValidationCache {
+    /// Creates an empty cache whose entries live for `ttl`.
+    ///
+    /// # Arguments
+    /// * `ttl` - How long a cached outcome stays valid.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            max_entries: MAX_ENTRIES,
+            entries: Arc::default()
+        }
+    }
+
+    /// Sets the maximum number of cached outcomes. Defaults to 10,000.
+    ///
+    /// # Arguments
+    /// * `max_entries` - The maximum number of entries kept.
+    pub fn set_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Returns the cached outcome for a rule and value, running `validate` on a miss or expired entry.
+    ///
+    /// # Arguments
+    /// * `rule` - The rule fingerprint, e.g. `Rule::fingerprint()` or any hashable description of the validator's configuration.
+    /// * `value` - The value being validated.
+    /// * `validate` - Runs the validation on a cache miss.
+    pub fn get_or_validate<K, V, F>(&self, rule: K, value: V, validate: F) -> Null<String>
+    where K: Hash, V: Hash, F: FnOnce() -> Null<String>
+    {
+        let key = (hash(rule), hash(value));
+        let now = Instant::now();
+
+        if let Some((outcome, _)) = self.get(key, now) {
+            return outcome;
+        }
+
+        let outcome = validate();
+        self.insert(key, now, outcome.clone(), None);
+
+        outcome
+    }
+
+    /// Returns the cached outcome like `get_or_validate`, keeping the unrendered message behind an error.
+    ///
+    /// An entry cached by `get_or_validate` holds no message, so it counts as a miss here.
+    pub(crate) fn get_or_check<K, V, F>(&self, rule: K, value: V, check: F) -> Null<Message>
+    where K: Hash, V: Hash, F: FnOnce() -> Null<Message>
+    {
+        let key = (hash(rule), hash(value));
+        let now = Instant::now();
+
+        match self.get(key, now) {
+            Some((Null::Value(_), Some(message))) => return Null::Value(message),
+            Some((Null::Null, _)) => return Null::Null,
+            Some((Null::Undefined, _)) => return Null::Undefined,
+            _ => {}
+        }
+
+        let outcome = check();
+        let message = match &outcome {
+            Null::Value(message) => Some(message.clone()),
+            _ => None
+        };
+
+        self.insert(key, now, message::render(outcome.clone()), message);
+
+        outcome
+    }
+
+    /// Returns the unexpired entry for a key.
+    fn get(&self, key: Key, now: Instant) -> Option<(Null<String>, Option<Message>)> {
+        let entries = self.entries.lock().ok()?;
+        let (created, outcome, message) = entries.outcomes.get(&key)?;
+
+        match now.duration_since(*created) < self.ttl {
+            true => Some((outcome.clone(), message.clone())),
+            false => None
+        }
+    }
+
+    /// Caches an outcome, pruning expired entries and evicting the oldest one when full.
+    fn insert(&self, key: Key, now: Instant, outcome: Null<String>, message: Option<Message>) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.prune(now, self.ttl);
+
+            while entries.outcomes.len() >= self.max_entries
+                && !entries.outcomes.contains_key(&key)
+                && entries.evict_oldest()
+            {}
+
+            if self.max_entries > 0 {
+                entries.outcomes.insert(key, (now, outcome, message));
+                entries.order.push_back((key, now));
+            }
+        }
+    }
+
+    /// Returns the number of cached outcomes, including expired ones not yet pruned.
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|entries| entries.outcomes.len()).unwrap_or_default()
+    }
+
+    /// Checks whether the cache holds no outcomes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cached outcome.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.outcomes.clear();
+            entries.order.clear();
+        }
+    }
+}
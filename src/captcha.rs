@@ -0,0 +1,66 @@
+use nulls::Null;
+use std::future::Future;
+
+use crate::{message, Validator};
+
+const MIN_CAPTCHA_SCORE: f64 = 0.5;
+
+/// The outcome of verifying a CAPTCHA token with its provider.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CaptchaVerdict {
+    /// The challenge was solved.
+    Human,
+    /// The token is invalid, expired or was already used.
+    Bot,
+    /// A risk score from `0.0` (bot) to `1.0` (human), as returned by reCAPTCHA v3.
+    Score(f64),
+    /// The provider could not be reached or returned an error.
+    Unavailable
+}
+
+/// A pluggable CAPTCHA provider such as reCAPTCHA, hCaptcha or Cloudflare Turnstile.
+///
+/// Implementations usually post the token, along with their secret and the client's IP, to the
+/// provider's `siteverify` endpoint. `async fn verify` can be used in implementations.
+pub trait CaptchaVerifier: Send + Sync {
+    /// Verifies a token submitted by the client.
+    fn verify(&self, token: &str) -> impl Future<Output = CaptchaVerdict> + Send;
+}
+
+impl Validator {
+    /// Sets the minimum score a scored CAPTCHA verdict must reach. Defaults to 0.5.
+    ///
+    /// # Arguments
+    /// * `min_captcha_score` - The minimum score, from `0.0` to `1.0`.
+    pub fn set_min_captcha_score(mut self, min_captcha_score: f64) -> Self {
+        self.min_captcha_score = Some(min_captcha_score);
+        self
+    }
+
+    /// Validates that the string value is a CAPTCHA token the verifier accepts.
+    ///
+    /// # Arguments
+    /// * `verifier` - The CAPTCHA provider.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the token is empty, rejected, scored too low, or the provider is unavailable.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub async fn validate_captcha_token<V>(&self, verifier: &V) -> Null<String>
+    where V: CaptchaVerifier
+    {
+        let token = self.string_value.trim();
+
+        if token.is_empty() {
+            return Null::Value(message::get(format!("{}-empty", self.field)));
+        }
+
+        let min = self.min_captcha_score.unwrap_or(MIN_CAPTCHA_SCORE);
+
+        match verifier.verify(token).await {
+            CaptchaVerdict::Human => Null::Undefined,
+            CaptchaVerdict::Score(score) if score >= min => Null::Undefined,
+            CaptchaVerdict::Unavailable => Null::Value(message::get(format!("{}-unavailable", self.field))),
+            _ => Null::Value(message::get(format!("{}-invalid", self.field)))
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use nulls::Null;
+
+use crate::{checksum, message, Validator};
+
+/// A card brand, identified by the IIN prefix ranges its numbers start with.
+struct Brand {
+    name: &'static str,
+    /// Ranges `(low, high)` matching numbers whose leading digits, as many as `low` has, fall within them.
+    prefixes: &'static [(u32, u32)],
+    lengths: &'static [usize]
+}
+
+/// Known card brands, checked in order.
+const BRANDS: [Brand; 8] = [
+    Brand { name: "visa", prefixes: &[(4, 4)], lengths: &[13, 16, 19] },
+    Brand { name: "mastercard", prefixes: &[(51, 55), (2221, 2720)], lengths: &[16] },
+    Brand { name: "amex", prefixes: &[(34, 34), (37, 37)], lengths: &[15] },
+    Brand { name: "discover", prefixes: &[(6011, 6011), (644, 649), (65, 65), (622126, 622925)], lengths: &[16, 17, 18, 19] },
+    Brand { name: "jcb", prefixes: &[(3528, 3589)], lengths: &[16, 17, 18, 19] },
+    Brand { name: "diners", prefixes: &[(300, 305), (36, 36), (38, 39)], lengths: &[14, 15, 16, 17, 18, 19] },
+    Brand { name: "unionpay", prefixes: &[(62, 62)], lengths: &[16, 17, 18, 19] },
+    Brand {
+        name: "maestro",
+        prefixes: &[(50, 50), (56, 58), (6304, 6304), (6759, 6759), (6761, 6763)],
+        lengths: &[12, 13, 14, 15, 16, 17, 18, 19]
+    }
+];
+
+/// Numbers of an unknown brand, which only need a plausible length.
+const UNKNOWN: Brand = Brand { name: "unknown", prefixes: &[], lengths: &[12, 13, 14, 15, 16, 17, 18, 19] };
+
+/// Detects the brand of a card number from its leading digits.
+fn detect_brand(digits: &[u8]) -> &'static Brand {
+    BRANDS.iter()
+        .find(|brand| brand.prefixes.iter().any(|(low, high)| {
+            let width = low.to_string().len();
+
+            digits.len() >= width
+                && (*low..=*high).contains(&digits[..width].iter().fold(0, |prefix, digit| prefix * 10 + u32::from(*digit)))
+        }))
+        .unwrap_or(&UNKNOWN)
+}
+
+impl Validator {
+    /// Sets the card brands that are accepted.
+    ///
+    /// # Arguments
+    /// * `allowed_brands` - A slice of brands: `visa`, `mastercard`, `amex`, `discover`, `jcb`,
+    ///   `diners`, `unionpay` or `maestro`. Defaults to any card.
+    pub fn set_allowed_brands<T>(mut self, allowed_brands: &[T]) -> Self
+    where T: ToString
+    {
+        self.allowed_brands = allowed_brands.iter().map(|brand| brand.to_string()).collect();
+        self
+    }
+
+    /// Validates that the string value is a payment card number with a valid Luhn check digit and a
+    /// length that matches its brand.
+    ///
+    /// Spaces and hyphens between digits are ignored. The brand is detected from the leading digits;
+    /// numbers of an unknown brand must be 12 to 19 digits and are only accepted when no brands are
+    /// set. A brand that is not accepted is reported with `{field}-brand-not-accepted`, carrying the
+    /// `brand` and `options` args.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, is not a card number of the right length,
+    ///   fails the Luhn check, or is of a brand that is not accepted.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_credit_card(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let digits = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .map(|c| c.to_digit(10).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()
+            .unwrap_or_default();
+
+        let brand = detect_brand(&digits);
+
+        if !brand.lengths.contains(&digits.len()) || !checksum::luhn(&digits) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let is_accepted = self.allowed_brands.is_empty()
+            || self.allowed_brands.iter().any(|allowed| allowed.eq_ignore_ascii_case(brand.name));
+
+        if !is_accepted {
+            return Null::Value(message::new(format!("{}-brand-not-accepted", self.field))
+                .set_args("brand", brand.name)
+                .set_args("options", self.format_options(&self.allowed_brands))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,99 @@
+use nulls::Null;
+
+use crate::{message, Message, Validator};
+
+/// Parses a character class such as `a-z0-9_-` into inclusive ranges.
+///
+/// `x-y` is a range, a `-` at either end is literal, and `\` escapes the next character.
+pub(crate) fn parse_class(class: &str) -> Vec<(char, char)> {
+    let mut chars = Vec::new();
+    let mut iter = class.chars();
+
+    while let Some(c) = iter.next() {
+        match c {
+            '\\' => chars.push((iter.next().unwrap_or('\\'), true)),
+            c => chars.push((c, false))
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, _) = chars[i];
+
+        match (chars.get(i + 1), chars.get(i + 2)) {
+            (Some(('-', false)), Some((end, _))) => {
+                ranges.push((start.min(*end), start.max(*end)));
+                i += 3;
+            },
+            _ => {
+                ranges.push((start, start));
+                i += 1;
+            }
+        }
+    }
+
+    ranges
+}
+
+/// Checks whether a character falls within any of the ranges.
+pub(crate) fn in_class(c: char, ranges: &[(char, char)]) -> bool {
+    ranges.iter().any(|(start, end)| (*start..=*end).contains(&c))
+}
+
+impl Validator {
+    /// Sets the characters the string value may contain, as a character class.
+    ///
+    /// # Arguments
+    /// * `allowed_chars` - The class, e.g. `a-z0-9_-`; a `-` at either end is literal and `\` escapes.
+    pub fn set_allowed_chars<T>(mut self, allowed_chars: T) -> Self
+    where T: ToString
+    {
+        self.allowed_chars = Some(allowed_chars.to_string());
+        self
+    }
+
+    /// Sets the characters the string value must not contain, as a character class.
+    ///
+    /// # Arguments
+    /// * `forbidden_chars` - The class, e.g. `<>"'`.
+    pub fn set_forbidden_chars<T>(mut self, forbidden_chars: T) -> Self
+    where T: ToString
+    {
+        self.forbidden_chars = Some(forbidden_chars.to_string());
+        self
+    }
+
+    /// Validates the string value against the allowed and forbidden character classes.
+    ///
+    /// The first offending character is reported in the `char` arg, with its character `position`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the string contains a forbidden character, or one outside the allowed class.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_chars(&self) -> Null<String> {
+        message::render(self.check_chars())
+    }
+
+    /// Runs `validate_chars`, returning the unrendered message behind its error.
+    pub(crate) fn check_chars(&self) -> Null<Message> {
+        let allowed = self.allowed_chars.as_deref().map(parse_class);
+        let forbidden = self.forbidden_chars.as_deref().map(parse_class);
+
+        for (position, c) in self.string_value.chars().enumerate() {
+            let key = match () {
+                _ if forbidden.as_ref().is_some_and(|forbidden| in_class(c, forbidden)) => "forbidden-char",
+                _ if allowed.as_ref().is_some_and(|allowed| !in_class(c, allowed)) => "invalid-char",
+                _ => continue
+            };
+
+            return Null::Value(message::new(format!("{}-{}", self.field, key))
+                .set_args("char", c.to_string())
+                .set_args("position", position.to_string())
+                .into_message());
+        }
+
+        Null::Undefined
+    }
+}
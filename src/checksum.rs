@@ -0,0 +1,49 @@
+/// Checks the Luhn (mod 10) check digit of a sequence of decimal digits, the last being the check digit.
+pub(crate) fn luhn(digits: &[u8]) -> bool {
+    if digits.is_empty() || digits.iter().any(|digit| *digit > 9) {
+        return false;
+    }
+
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| match index % 2 {
+            1 if *digit * 2 > 9 => u32::from(*digit * 2 - 9),
+            1 => u32::from(*digit * 2),
+            _ => u32::from(*digit)
+        })
+        .sum::<u32>();
+
+    sum.is_multiple_of(10)
+}
+
+/// Computes the GS1 (mod 10) check digit used by GTINs, SSCCs and many tracking numbers.
+///
+/// Digits are weighted 3 and 1 alternately, starting with 3 next to the check digit.
+pub(crate) fn gs1_check_digit(data: &[u8]) -> u8 {
+    let sum = data
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| u32::from(*digit) * if index % 2 == 0 { 3 } else { 1 })
+        .sum::<u32>();
+
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// Checks the GS1 (mod 10) check digit of a sequence of decimal digits, the last being the check digit.
+pub(crate) fn gs1(digits: &[u8]) -> bool {
+    match digits.split_last() {
+        Some((check, data)) => gs1_check_digit(data) == *check,
+        None => false
+    }
+}
+
+/// Converts an ASCII digit string into digit values.
+pub(crate) fn digits(value: &str) -> Option<Vec<u8>> {
+    value
+        .bytes()
+        .map(|byte| byte.is_ascii_digit().then(|| byte - b'0'))
+        .collect::<Option<Vec<u8>>>()
+}
@@ -0,0 +1,247 @@
+use nulls::Null;
+
+use crate::{message, presets, Validator};
+
+const MIN_CONTRAST: f64 = 4.5;
+
+/// Parses a `#rgb` or `#rrggbb` hex color into its red, green and blue channels.
+pub(crate) fn parse_hex(value: &str) -> Option<[u8; 3]> {
+    let digits = value.strip_prefix('#')?;
+
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |index: usize, width: usize| {
+        let channel = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16).ok()?;
+
+        Some(match width {
+            1 => channel * 0x11,
+            _ => channel
+        })
+    };
+
+    match digits.len() {
+        3 => Some([channel(0, 1)?, channel(1, 1)?, channel(2, 1)?]),
+        6 => Some([channel(0, 2)?, channel(1, 2)?, channel(2, 2)?]),
+        _ => None
+    }
+}
+
+/// Parses a CSS number such as `50`, `.5` or `-1.25`.
+fn css_number(value: &str) -> Option<f64> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+
+    match digits.bytes().all(|byte| byte.is_ascii_digit() || byte == b'.') && digits.bytes().any(|byte| byte.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None
+    }
+}
+
+/// Checks a color component: a number from 0 to `max`, or a percentage from 0% to 100%.
+fn is_component(value: &str, max: f64) -> bool {
+    match value.strip_suffix('%') {
+        Some(percent) => css_number(percent).is_some_and(|percent| (0.0..=100.0).contains(&percent)),
+        None => css_number(value).is_some_and(|number| (0.0..=max).contains(&number))
+    }
+}
+
+/// Checks a hue, a number of degrees optionally suffixed with `deg`, `grad`, `rad` or `turn`.
+fn is_hue(value: &str) -> bool {
+    let value = ["deg", "grad", "rad", "turn"]
+        .iter()
+        .find_map(|unit| value.strip_suffix(unit))
+        .unwrap_or(value);
+
+    css_number(value).is_some()
+}
+
+/// Checks a lowercase `rgb()`, `rgba()`, `hsl()` or `hsla()` color in either the legacy comma-separated
+/// or the modern space-separated syntax, e.g. `rgb(255, 0, 0)` or `hsl(120deg 100% 50% / 0.5)`.
+fn is_css_function(value: &str) -> bool {
+    let Some((name, args)) = value.strip_suffix(')').and_then(|value| value.split_once('(')) else {
+        return false;
+    };
+
+    let (mut components, alpha) = match args.split_once('/') {
+        Some((components, alpha)) if !args.contains(',') => (components.split_whitespace().collect::<Vec<&str>>(), Some(alpha.trim())),
+        _ if !args.contains(',') => (args.split_whitespace().collect(), None),
+        _ => (args.split(',').map(str::trim).collect(), None)
+    };
+
+    let alpha = match (alpha, components.len()) {
+        (None, 4) if args.contains(',') => components.pop(),
+        (alpha, _) => alpha
+    };
+
+    if components.len() != 3 || !alpha.is_none_or(|alpha| is_component(alpha, 1.0)) {
+        return false;
+    }
+
+    match name.trim() {
+        "rgb" | "rgba" => components.iter().all(|component| is_component(component, 255.0)),
+        "hsl" | "hsla" => is_hue(components[0]) && components[1..].iter().all(|component| {
+            component.ends_with('%') && is_component(component, 100.0)
+        }),
+        _ => false
+    }
+}
+
+/// Computes the WCAG 2 relative luminance of an sRGB color.
+fn luminance(rgb: [u8; 3]) -> f64 {
+    let [r, g, b] = rgb.map(|channel| {
+        let channel = f64::from(channel) / 255.0;
+
+        match channel <= 0.040_45 {
+            true => channel / 12.92,
+            false => ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Computes the WCAG 2 contrast ratio between two colors, from `1.0` to `21.0`.
+pub(crate) fn contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+impl Validator {
+    /// Sets the other color field the string value's color must contrast with.
+    ///
+    /// # Arguments
+    /// * `field` - The other field's name, used in the error message.
+    /// * `color` - The other field's color, as `#rgb` or `#rrggbb`.
+    pub fn set_contrast_with<T, U>(mut self, field: T, color: U) -> Self
+    where T: ToString, U: ToString
+    {
+        self.contrast_with = Some((field.to_string(), color.to_string()));
+        self
+    }
+
+    /// Sets the minimum WCAG contrast ratio. Defaults to 4.5, the AA level for normal text.
+    ///
+    /// # Arguments
+    /// * `min_contrast` - The minimum ratio, from `1.0` to `21.0`.
+    pub fn set_min_contrast(mut self, min_contrast: f64) -> Self {
+        self.min_contrast = Some(min_contrast);
+        self
+    }
+
+    /// Allows hex colors with an alpha channel, as `#rrggbbaa`.
+    ///
+    /// # Arguments
+    /// * `is_alpha_allowed` - Whether the eight-digit form is accepted.
+    pub fn set_as_alpha_allowed(mut self, is_alpha_allowed: bool) -> Self {
+        self.is_alpha_allowed = is_alpha_allowed;
+        self
+    }
+
+    /// Validates that the string value is a `#rgb` or `#rrggbb` hex color, or `#rrggbbaa` when alpha is
+    /// allowed. Digits are case-insensitive.
+    ///
+    /// An eight-digit color is reported with `{field}-alpha` when alpha is not allowed.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, is not a hex color, or has a disallowed alpha channel.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_hex_color(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let digits = value.strip_prefix('#').unwrap_or_default();
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        match (digits.len(), self.is_alpha_allowed) {
+            (3 | 6, _) | (8, true) => Null::Undefined,
+            (8, false) => Null::Value(message::get(format!("{}-alpha", self.field))),
+            _ => Null::Value(message::get(format!("{}-invalid", self.field)))
+        }
+    }
+
+    /// Validates that the string value is a CSS color: a hex color, an `rgb()`, `rgba()`, `hsl()` or
+    /// `hsla()` function, or one of `presets::css_colors()`.
+    ///
+    /// Hex colors may have 3, 4, 6 or 8 digits. Functions accept both the comma-separated and the
+    /// `rgb(255 0 0 / 50%)` syntax, and components must be within range, e.g. channels within 0 to 255.
+    /// Names and function names are case-insensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or the value is not a CSS color.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_css_color(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = value.to_ascii_lowercase();
+
+        let is_color = match value.strip_prefix('#') {
+            Some(digits) => matches!(digits.len(), 3 | 4 | 6 | 8) && digits.bytes().all(|byte| byte.is_ascii_hexdigit()),
+            None => presets::css_colors().contains(&value.as_str()) || is_css_function(&value)
+        };
+
+        if !is_color {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value's color has enough WCAG contrast with the other color field.
+    ///
+    /// Both colors are `#rgb` or `#rrggbb` hex colors. The check is skipped when the other color is
+    /// unset or malformed, since that field reports its own error.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, the color is malformed, or the contrast is too low.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_contrast_ratio(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(color) = parse_hex(self.string_value.trim()) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let Some((other_field, other)) = &self.contrast_with else {
+            return Null::Undefined;
+        };
+
+        let Some(other) = parse_hex(other.trim()) else {
+            return Null::Undefined;
+        };
+
+        let min = self.min_contrast.unwrap_or(MIN_CONTRAST);
+        let ratio = contrast_ratio(color, other);
+
+        if ratio < min {
+            return Null::Value(message::new(format!("{}-contrast", self.field))
+                .set_args("ratio", format!("{:.2}", ratio))
+                .set_args("min", min)
+                .set_args("other", other_field)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "confusables")]
+use nulls::Null;
+
+#[cfg(feature = "confusables")]
+use crate::message;
+use crate::Validator;
+
+/// Returns the confusable skeleton of a value, per Unicode Technical Standard #39.
+///
+/// Two values with the same skeleton look alike (e.g. `admin` and `аdmin` with a Cyrillic `а`).
+/// The value is lowercased first, so the skeleton can be stored alongside handles for
+/// case-insensitive, storage-side deduplication.
+///
+/// # Arguments
+/// * `value` - The value to reduce.
+#[cfg(feature = "confusables")]
+pub fn confusable_skeleton(value: &str) -> String {
+    unicode_security::confusable_detection::skeleton(&value.to_lowercase()).collect()
+}
+
+impl Validator {
+    /// Sets the names the string value must not visually imitate.
+    ///
+    /// # Arguments
+    /// * `protected_names` - Existing or reserved names, e.g. admin and staff handles.
+    pub fn set_protected_names<T>(mut self, protected_names: &[T]) -> Self
+    where T: ToString
+    {
+        self.protected_names = protected_names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Validates that the string value is not confusable with any protected name.
+    ///
+    /// Values are compared by their `confusable_skeleton`, so mixed-script lookalikes such as
+    /// `раураl` (Cyrillic `р` and `а`) are caught as well as exact matches.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the string is empty or visually imitates a protected name.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "confusables")]
+    pub fn validate_no_confusables(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let skeleton = confusable_skeleton(&self.string_value);
+
+        if let Some(name) = self.protected_names.iter().find(|name| confusable_skeleton(name) == skeleton) {
+            return Null::Value(message::new(format!("{}-confusable", self.field))
+                .set_args("name", name.to_string())
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
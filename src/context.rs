@@ -0,0 +1,92 @@
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::Validator;
+
+/// Request-scoped data that validators may consult, such as the acting user or related field values.
+///
+/// A context is built once per request and handed to each validator through `set_context`.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    pub actor: Option<String>,
+    pub assignable_roles: HashMap<String, Vec<String>>,
+    values: Map<String, Value>
+}
+
+impl Context {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the role of the actor performing the request.
+    ///
+    /// # Arguments
+    /// * `actor` - The actor's role, e.g. `"manager"`.
+    pub fn set_actor<T>(mut self, actor: T) -> Self
+    where T: ToString
+    {
+        self.actor = Some(actor.to_string());
+        self
+    }
+
+    /// Sets the roles an actor may assign to others.
+    ///
+    /// Can be called once per actor role; together the calls form the role matrix.
+    ///
+    /// # Arguments
+    /// * `actor` - The actor's role.
+    /// * `roles` - A slice of roles the actor may assign.
+    pub fn set_assignable_roles<T, U>(mut self, actor: T, roles: &[U]) -> Self
+    where T: ToString, U: ToString
+    {
+        self.assignable_roles.insert(
+            actor.to_string(),
+            roles.iter().map(|role| role.to_string()).collect::<Vec<String>>()
+        );
+
+        self
+    }
+
+    /// Sets a named value, such as another field of the same form.
+    ///
+    /// # Arguments
+    /// * `key` - The value's name.
+    /// * `value` - The value.
+    pub fn set_value<T, U>(mut self, key: T, value: U) -> Self
+    where T: ToString, U: Into<Value>
+    {
+        self.values.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Creates a context whose named values are the fields of a JSON object payload.
+    ///
+    /// # Arguments
+    /// * `payload` - The payload; anything but an object yields an empty context.
+    pub fn from_payload(payload: &Value) -> Self {
+        Self {
+            values: payload.as_object().cloned().unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a named value, if set.
+    ///
+    /// # Arguments
+    /// * `key` - The value's name.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+}
+
+impl Validator {
+    /// Sets the request context validators may consult.
+    ///
+    /// # Arguments
+    /// * `context` - The context.
+    pub fn set_context(mut self, context: &Context) -> Self {
+        self.context = context.clone();
+        self
+    }
+}
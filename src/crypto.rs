@@ -0,0 +1,270 @@
+#[cfg(feature = "crypto")]
+use nulls::Null;
+#[cfg(feature = "crypto")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "crypto")]
+use sha3::Keccak256;
+
+#[cfg(feature = "crypto")]
+use crate::message;
+use crate::Validator;
+
+#[cfg(feature = "crypto")]
+const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// The longest base58 encoding of a 25-byte base58check payload.
+#[cfg(feature = "crypto")]
+const BASE58_ADDRESS_MAX: usize = 35;
+#[cfg(feature = "crypto")]
+const BECH32: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+#[cfg(feature = "crypto")]
+const BECH32M: u32 = 0x2bc8_30a3;
+
+impl Validator {
+    /// Configures whether cryptocurrency addresses are for a test network instead of mainnet.
+    ///
+    /// # Arguments
+    /// * `is_testnet` - A boolean indicating if testnet addresses are expected.
+    pub fn set_as_testnet(mut self, is_testnet: bool) -> Self {
+        self.is_testnet = is_testnet;
+        self
+    }
+
+    /// Configures whether addresses must carry their optional checksum, such as EIP-55 mixed case.
+    ///
+    /// # Arguments
+    /// * `is_checksum_required` - A boolean indicating if addresses without a checksum are rejected.
+    pub fn set_as_checksum_required(mut self, is_checksum_required: bool) -> Self {
+        self.is_checksum_required = is_checksum_required;
+        self
+    }
+
+    /// Validates that the string value is a Bitcoin address.
+    ///
+    /// Legacy addresses are base58check with a P2PKH or P2SH version byte, and SegWit addresses are
+    /// bech32 (version 0) or bech32m (version 1 and up), for mainnet or, with `is_testnet`, testnet.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, for the wrong network, or has a wrong checksum.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "crypto")]
+    pub fn validate_btc_address(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let hrp = match self.is_testnet {
+            true => "tb",
+            false => "bc"
+        };
+
+        let is_valid = match value.to_ascii_lowercase().starts_with(&format!("{}1", hrp)) {
+            true => is_segwit_address(value, hrp),
+            false => is_base58_address(value, self.is_testnet)
+        };
+
+        match is_valid {
+            true => Null::Undefined,
+            false => Null::Value(message::get(format!("{}-invalid", self.field)))
+        }
+    }
+
+    /// Validates that the string value is an Ethereum address: `0x` followed by 40 hex digits.
+    ///
+    /// Mixed-case addresses must match their EIP-55 checksum. All-lowercase or all-uppercase
+    /// addresses carry no checksum and are only accepted unless `is_checksum_required` is set.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or has a wrong or missing checksum.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "crypto")]
+    pub fn validate_eth_address(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(hex) = value.strip_prefix("0x").filter(|hex| {
+            hex.len() == 40 && hex.bytes().all(|byte| byte.is_ascii_hexdigit())
+        }) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let is_mixed_case = hex.bytes().any(|byte| byte.is_ascii_lowercase())
+            && hex.bytes().any(|byte| byte.is_ascii_uppercase());
+
+        if !is_mixed_case {
+            return match self.is_checksum_required {
+                true => Null::Value(message::get(format!("{}-checksum", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let hash = Keccak256::digest(hex.to_ascii_lowercase().as_bytes());
+        let is_checksum = hex.bytes().enumerate().all(|(index, byte)| {
+            let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+
+            match byte.is_ascii_alphabetic() {
+                true => byte.is_ascii_uppercase() == (nibble >= 8),
+                false => true
+            }
+        });
+
+        match is_checksum {
+            true => Null::Undefined,
+            false => Null::Value(message::get(format!("{}-checksum", self.field)))
+        }
+    }
+}
+
+/// Checks a base58check P2PKH or P2SH address: a version byte, a 20-byte hash and a 4-byte
+/// double-SHA-256 checksum.
+#[cfg(feature = "crypto")]
+fn is_base58_address(value: &str, is_testnet: bool) -> bool {
+    // Bounds the quadratic decode; longer strings cannot hold a 25-byte payload anyway
+    if value.len() > BASE58_ADDRESS_MAX {
+        return false;
+    }
+
+    let Some(bytes) = base58_decode(value) else {
+        return false;
+    };
+
+    if bytes.len() != 25 {
+        return false;
+    }
+
+    let versions: [u8; 2] = match is_testnet {
+        true => [0x6f, 0xc4],
+        false => [0x00, 0x05]
+    };
+
+    let (payload, checksum) = bytes.split_at(21);
+    let hash = Sha256::digest(Sha256::digest(payload));
+
+    versions.contains(&payload[0]) && hash[..4] == *checksum
+}
+
+/// Decodes a base58 string, keeping leading `1`s as zero bytes.
+#[cfg(feature = "crypto")]
+fn base58_decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::<u8>::new();
+
+    for symbol in value.bytes() {
+        let mut carry = BASE58.iter().position(|digit| *digit == symbol)? as u32;
+
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.insert(0, (carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = value.bytes().take_while(|symbol| *symbol == b'1').count();
+    let mut decoded = vec![0; zeros];
+    decoded.extend(bytes);
+
+    Some(decoded)
+}
+
+/// Checks a bech32 (witness version 0) or bech32m (version 1 and up) SegWit address per BIP 173 and BIP 350.
+#[cfg(feature = "crypto")]
+fn is_segwit_address(value: &str, hrp: &str) -> bool {
+    let has_lower = value.bytes().any(|byte| byte.is_ascii_lowercase());
+    let has_upper = value.bytes().any(|byte| byte.is_ascii_uppercase());
+
+    if (has_lower && has_upper) || value.len() > 90 {
+        return false;
+    }
+
+    let value = value.to_ascii_lowercase();
+    let Some(data) = value[hrp.len() + 1..]
+        .bytes()
+        .map(|symbol| BECH32.iter().position(|digit| *digit == symbol).map(|index| index as u8))
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return false;
+    };
+
+    if data.len() < 7 {
+        return false;
+    }
+
+    let mut values = hrp.bytes().map(|byte| byte >> 5).collect::<Vec<u8>>();
+    values.push(0);
+    values.extend(hrp.bytes().map(|byte| byte & 0x1f));
+    values.extend(&data);
+
+    let version = data[0];
+    let constant = match version {
+        0 => 1,
+        1..=16 => BECH32M,
+        _ => return false
+    };
+
+    if bech32_polymod(&values) != constant {
+        return false;
+    }
+
+    let Some(program) = convert_bits(&data[1..data.len() - 6]) else {
+        return false;
+    };
+
+    match version {
+        0 => program.len() == 20 || program.len() == 32,
+        _ => (2..=40).contains(&program.len())
+    }
+}
+
+/// Computes the bech32 checksum polynomial over 5-bit values.
+#[cfg(feature = "crypto")]
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+
+    values.iter().fold(1u32, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(*value);
+
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| (top >> index) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+/// Regroups 5-bit values into bytes, rejecting non-zero or oversized padding.
+#[cfg(feature = "crypto")]
+fn convert_bits(data: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut accumulator = 0u32;
+    let mut bits = 0u32;
+
+    for value in data {
+        accumulator = (accumulator << 5) | u32::from(*value);
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push(((accumulator >> bits) & 0xff) as u8);
+        }
+    }
+
+    match bits < 5 && (accumulator << (8 - bits)) & 0xff == 0 {
+        true => Some(bytes),
+        false => None
+    }
+}
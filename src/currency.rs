@@ -0,0 +1,122 @@
+use nulls::Null;
+
+use crate::{message, presets, Validator};
+
+impl Validator {
+    /// Sets the maximum number of digits after the decimal point.
+    ///
+    /// # Arguments
+    /// * `max_decimals` - The maximum number of fractional digits.
+    pub fn set_max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Validates that the string value is a currency pair such as `USD/EUR`.
+    ///
+    /// Both codes must be known and distinct. Codes are checked against the option list when one is
+    /// set, and against the active ISO 4217 codes otherwise.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, names an unknown currency, or repeats a currency.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_currency_pair(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some((base, quote)) = self.string_value.trim().split_once('/') else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        for code in [base, quote] {
+            if code.len() != 3 || !code.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+                return Null::Value(message::get(format!("{}-invalid", self.field)));
+            }
+
+            if !self.is_currency(code) {
+                return Null::Value(message::new(format!("{}-currency", self.field))
+                    .set_args("currency", self.list_formatter().quote(code))
+                    .build());
+            }
+        }
+
+        if base.eq_ignore_ascii_case(quote) {
+            return Null::Value(message::new(format!("{}-same-currency", self.field))
+                .set_args("currency", self.list_formatter().quote(base))
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value is an exchange rate: a positive decimal within the bounds and
+    /// precision.
+    ///
+    /// The value is checked as written, so `1.10` counts two fractional digits even though it equals `1.1`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a positive decimal, out of bounds, or too precise.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_exchange_rate(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let is_decimal = !whole.is_empty()
+            && whole.bytes().all(|byte| byte.is_ascii_digit())
+            && fraction.bytes().all(|byte| byte.is_ascii_digit())
+            && !value.ends_with('.');
+
+        let Some(rate) = value.parse::<f64>().ok().filter(|_| is_decimal) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        if rate <= 0.0 {
+            return Null::Value(message::get(format!("{}-positive", self.field)));
+        }
+
+        if let Some(min) = self.fmin
+            && rate < min
+        {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if let Some(max) = self.fmax
+            && rate > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if let Some(max_decimals) = self.max_decimals
+            && fraction.len() > max_decimals
+        {
+            return Null::Value(message::new(format!("{}-decimals", self.field))
+                .set_args("max", max_decimals)
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Checks whether a code is a known currency, honoring a custom option list.
+    fn is_currency(&self, code: &str) -> bool {
+        match &self.option_list_string {
+            Some(list) => list.iter().any(|item| item.eq_ignore_ascii_case(code)),
+            None => presets::currencies().iter().any(|item| item.eq_ignore_ascii_case(code))
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use nulls::Null;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError, SyncSender};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use crate::{message, Message, Validator};
+
+const MAX_WORKERS: usize = 8;
+const QUEUE_PER_WORKER: usize = 4;
+
+/// A validation queued for a worker thread.
+type Job = Box<dyn FnOnce() + Send>;
+
+static POOL: OnceLock<SyncSender<Job>> = OnceLock::new();
+
+/// Returns the queue of the shared worker pool, starting its threads on first use.
+///
+/// The pool has one worker per available core, up to 8, and a bounded queue, so validations that
+/// keep running past their deadline occupy a fixed number of threads instead of piling up new ones.
+fn pool() -> &'static SyncSender<Job> {
+    POOL.get_or_init(|| {
+        let workers = thread::available_parallelism().map_or(1, |workers| workers.get()).min(MAX_WORKERS);
+        let (sender, receiver) = mpsc::sync_channel::<Job>(workers * QUEUE_PER_WORKER);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..workers {
+            let receiver = receiver.clone();
+
+            thread::spawn(move || loop {
+                let job = receiver.lock().unwrap_or_else(PoisonError::into_inner).recv();
+
+                match job {
+                    // A panicking validation must not take its worker down with it
+                    Ok(job) => {
+                        let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                    },
+                    Err(_) => break
+                }
+            });
+        }
+
+        sender
+    })
+}
+
+impl Validator {
+    /// Sets the maximum time regex-heavy and async validators may run before giving up.
+    ///
+    /// # Arguments
+    /// * `timeout` - The deadline for a single validation.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Runs a validation on a shared worker pool, returning `{field}-timeout` if it misses the deadline.
+    ///
+    /// Without a timeout the validation runs inline. A validation that times out keeps its worker busy
+    /// until it finishes, but the caller is no longer blocked on it; when every worker is busy and the
+    /// queue is full, the validation times out at once instead of starting another thread.
+    ///
+    /// # Arguments
+    /// * `validate` - The validation to run, given a copy of this validator without the timeout.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the validation fails, panics, exceeds the timeout, or cannot be queued.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_with_timeout<F>(&self, validate: F) -> Null<String>
+    where F: FnOnce(&Validator) -> Null<String> + Send + 'static
+    {
+        self.run_with_timeout(validate).unwrap_or_else(|error| Null::Value(error.render()))
+    }
+
+    /// Runs a validation like `validate_with_timeout`, keeping whatever outcome type it returns.
+    ///
+    /// # Returns
+    /// * `Ok(Null<T>)` - The validation's own outcome.
+    /// * `Err(Message)` - If the validation panics, exceeds the timeout, or cannot be queued.
+    pub(crate) fn run_with_timeout<T, F>(&self, validate: F) -> Result<Null<T>, Message>
+    where T: Send + 'static, F: FnOnce(&Validator) -> Null<T> + Send + 'static
+    {
+        let Some(timeout) = self.timeout else {
+            return Ok(validate(self));
+        };
+
+        let validator = Validator { timeout: None, ..self.clone() };
+        let (sender, receiver) = mpsc::channel();
+
+        let job: Job = Box::new(move || {
+            let _ = sender.send(validate(&validator));
+        });
+
+        let outcome = match pool().try_send(job) {
+            Ok(()) => receiver.recv_timeout(timeout),
+            Err(_) => Err(RecvTimeoutError::Timeout)
+        };
+
+        match outcome {
+            Ok(outcome) => Ok(outcome),
+            Err(RecvTimeoutError::Timeout) => Err(message::new(format!("{}-timeout", self.field))
+                .set_args("timeout", timeout.as_millis().to_string())
+                .into_message()),
+            Err(RecvTimeoutError::Disconnected) => Err(message::key(format!("{}-invalid", self.field)))
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use nulls::Null;
+use serde_json::Value;
+
+use crate::{message, Rule, RuleKind, Validator};
+
+/// The keys `Rule::from_definition` understands.
+const RULE_KEYS: &[&str] = &["type", "required", "case_sensitive", "min", "max", "fmin", "fmax", "options", "default"];
+
+impl Validator {
+    /// Validates a user-authored rule or form definition before it is saved.
+    ///
+    /// A definition whose keys are all rule keys is checked as a single rule, e.g.
+    /// `{"type": "int", "min": 18}`; any other object is checked as a form mapping field names to rule
+    /// definitions. Each rule must name a known type, have well-typed keys, keep `min` ≤ `max` and
+    /// `fmin` ≤ `fmax`, list at least one option for `options` rules, and have a default that passes
+    /// the rule itself. In forms, unknown rule keys are rejected too. Errors carry the offending form
+    /// field as the `path` arg.
+    ///
+    /// # Arguments
+    /// * `definition` - The rule or form definition.
+    ///
+    /// # Returns
+    /// * `Null::Value` - The first inconsistency found.
+    /// * `Null::Undefined` - If the definition is consistent.
+    pub fn validate_rule_definition(&self, definition: &Value) -> Null<String> {
+        let Some(object) = definition.as_object() else {
+            return Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("path", "")
+                .build());
+        };
+
+        if object.keys().all(|key| RULE_KEYS.contains(&key.as_str())) {
+            return self.check_rule_definition("", definition);
+        }
+
+        for (path, rule) in object {
+            if let Some(key) = rule
+                .as_object()
+                .and_then(|rule| rule.keys().find(|key| !RULE_KEYS.contains(&key.as_str())))
+            {
+                return Null::Value(message::new(format!("{}-unknown-key", self.field))
+                    .set_args("path", path)
+                    .set_args("key", key)
+                    .build());
+            }
+
+            let outcome = self.check_rule_definition(path, rule);
+
+            if outcome.is_some() {
+                return outcome;
+            }
+        }
+
+        Null::Undefined
+    }
+
+    /// Checks a single rule definition, reporting errors against the given form path.
+    fn check_rule_definition(&self, path: &str, definition: &Value) -> Null<String> {
+        let error = |key: &str| message::new(format!("{}-{}", self.field, key)).set_args("path", path);
+
+        if let Some(kind) = definition.get("type").and_then(Value::as_str)
+            && RuleKind::from_name(kind).is_none()
+        {
+            return Null::Value(error("unknown-type").set_args("type", kind).build());
+        }
+
+        let Some(rule) = Rule::from_definition(definition) else {
+            return Null::Value(error("invalid").build());
+        };
+
+        if let (Some(min), Some(max)) = (rule.min, rule.max)
+            && min > max
+        {
+            return Null::Value(error("min-max").set_args("min", min).set_args("max", max).build());
+        }
+
+        if let (Some(fmin), Some(fmax)) = (rule.fmin, rule.fmax)
+            && fmin > fmax
+        {
+            return Null::Value(error("min-max").set_args("min", fmin).set_args("max", fmax).build());
+        }
+
+        let is_options_empty = rule.options.as_ref().is_none_or(|options| options.iter().next().is_none());
+
+        if rule.kind == RuleKind::Options && is_options_empty {
+            return Null::Value(error("options-empty").build());
+        }
+
+        if let Some(default) = &rule.default
+            && rule.validate(&self.field, Some(default)).is_some()
+        {
+            return Null::Value(error("default-invalid").build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,63 @@
+use nulls::Null;
+
+use crate::hostname::is_hostname;
+use crate::{message, Validator};
+
+impl Validator {
+    /// Requires the domain to be registrable, i.e. to have a label of its own below its public suffix,
+    /// so `foo.co.uk` is accepted but the bare suffix `co.uk` is not.
+    ///
+    /// # Arguments
+    /// * `is_registrable_required` - Whether a bare public suffix is rejected.
+    pub fn set_require_registrable(mut self, is_registrable_required: bool) -> Self {
+        self.is_registrable_required = is_registrable_required;
+        self
+    }
+
+    /// Validates that the string value is a domain name ending in a public suffix from the bundled
+    /// public suffix list, such as `example.com` or `foo.co.uk`.
+    ///
+    /// The name must be a valid host name of at least two labels. Unlisted suffixes such as `.local`
+    /// are rejected with `{field}-suffix`; with `set_require_registrable(true)`, a bare public suffix is
+    /// rejected with `{field}-not-registrable`. The suffix is reported in the `suffix` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a host name, has an unlisted suffix,
+    ///   or is a bare public suffix when a registrable domain is required.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_domain(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = value.strip_suffix('.').unwrap_or(value).to_ascii_lowercase();
+
+        if !is_hostname(&value) || !value.contains('.') {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let suffix = match psl::suffix(value.as_bytes()) {
+            Some(suffix) if suffix.is_known() => String::from_utf8_lossy(suffix.as_bytes()).to_string(),
+            _ => {
+                let suffix = value.rsplit('.').next().unwrap_or_default();
+
+                return Null::Value(message::new(format!("{}-suffix", self.field))
+                    .set_args("suffix", suffix)
+                    .build());
+            }
+        };
+
+        if self.is_registrable_required && psl::domain_str(&value).is_none() {
+            return Null::Value(message::new(format!("{}-not-registrable", self.field))
+                .set_args("suffix", suffix)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
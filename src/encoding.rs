@@ -0,0 +1,198 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// Computes the decoded size of a base64 string from its length, without decoding it.
+///
+/// Works for padded and unpadded input. Returns `None` when the length cannot be valid base64.
+///
+/// # Arguments
+/// * `encoded` - The base64 encoded string.
+pub(crate) fn b64_decoded_len(encoded: &str) -> Option<usize> {
+    let padding = encoded.bytes().rev().take_while(|b| *b == b'=').count();
+
+    if padding > 2 {
+        return None;
+    }
+
+    let len = encoded.len() - padding;
+
+    match len % 4 {
+        1 => None,
+        remainder => Some(len / 4 * 3 + remainder * 3 / 4)
+    }
+}
+
+/// Canonical charset names and their accepted labels.
+const CHARSETS: [(&str, &[&str]); 7] = [
+    ("utf-8", &["utf-8", "utf8", "unicode-1-1-utf-8"]),
+    ("us-ascii", &["us-ascii", "ascii", "ansi_x3.4-1968"]),
+    ("iso-8859-1", &["iso-8859-1", "iso8859-1", "latin1", "l1"]),
+    ("windows-1252", &["windows-1252", "cp1252", "x-cp1252"]),
+    ("utf-16", &["utf-16", "utf16"]),
+    ("utf-16le", &["utf-16le"]),
+    ("utf-16be", &["utf-16be"])
+];
+
+/// Resolves a charset label to its canonical name.
+///
+/// # Arguments
+/// * `label` - The declared charset label, matched case-insensitively.
+fn canonical_charset(label: &str) -> Option<&'static str> {
+    let label = label.trim().to_lowercase();
+
+    CHARSETS.iter()
+        .find(|(_, labels)| labels.contains(&label.as_str()))
+        .map(|(name, _)| *name)
+}
+
+/// Checks whether the bytes at the start of the slice encode a UTF-16 surrogate as in WTF-8.
+fn is_wtf8_surrogate(bytes: &[u8]) -> bool {
+    matches!(bytes, [0xED, 0xA0..=0xBF, 0x80..=0xBF, ..])
+}
+
+impl Validator {
+    /// Sets the raw bytes value for the validator, defaulting to an empty vector if null or undefined.
+    ///
+    /// # Arguments
+    /// * `bytes` - A nullable byte vector.
+    pub fn set_bytes_value(mut self, bytes: &Null<Vec<u8>>) -> Self {
+        self.bytes_value = bytes.clone().take().unwrap_or_default();
+        self
+    }
+
+    /// Configures whether a leading UTF-8 byte order mark is accepted.
+    ///
+    /// # Arguments
+    /// * `is_bom_allowed` - A boolean indicating if a leading `EF BB BF` is allowed.
+    pub fn set_as_bom_allowed(mut self, is_bom_allowed: bool) -> Self {
+        self.is_bom_allowed = is_bom_allowed;
+        self
+    }
+
+    /// Configures whether lone surrogates encoded as in WTF-8 are accepted.
+    ///
+    /// # Arguments
+    /// * `is_surrogates_allowed` - A boolean indicating if WTF-8 surrogate sequences are allowed.
+    pub fn set_as_surrogates_allowed(mut self, is_surrogates_allowed: bool) -> Self {
+        self.is_surrogates_allowed = is_surrogates_allowed;
+        self
+    }
+
+    /// Validates that the bytes value is valid UTF-8.
+    ///
+    /// Errors carry the byte `position` of the first offending sequence. Lone surrogates are
+    /// reported through `{field}-surrogate` and byte order marks through `{field}-bom`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, starts with a forbidden BOM, or is not valid UTF-8.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_utf8(&self) -> Null<String> {
+        if self.bytes_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if !self.is_bom_allowed && self.bytes_value.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            return Null::Value(message::get(format!("{}-bom", self.field)));
+        }
+
+        let mut position = 0;
+
+        while let Err(error) = std::str::from_utf8(&self.bytes_value[position..]) {
+            position += error.valid_up_to();
+
+            let key = match is_wtf8_surrogate(&self.bytes_value[position..]) {
+                true if self.is_surrogates_allowed => {
+                    position += 3;
+                    continue;
+                },
+                true => format!("{}-surrogate", self.field),
+                false => format!("{}-invalid", self.field)
+            };
+
+            return Null::Value(message::new(key)
+                .set_args("position", position.to_string())
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value is a known charset label, restricted to the option list if set,
+    /// and that the bytes value, if any, is valid in that charset.
+    ///
+    /// Supported charsets are UTF-8, US-ASCII, ISO-8859-1, Windows-1252 and UTF-16 (LE/BE).
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, the charset is unknown or not allowed, or the bytes do not conform.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_encoding(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let charset = canonical_charset(&self.string_value).filter(|charset| {
+            self.option_list_string
+                .as_ref()
+                .is_none_or(|list| list.iter().any(|item| canonical_charset(item) == Some(*charset)))
+        });
+
+        let Some(charset) = charset else {
+            return Null::Value(message::new(format!("{}-unsupported", self.field))
+                .set_args("charset", self.string_value.as_str())
+                .build());
+        };
+
+        let is_conforming = match charset {
+            "utf-8" if !self.bytes_value.is_empty() => return self.validate_utf8(),
+            "us-ascii" => self.bytes_value.is_ascii(),
+            "utf-16" | "utf-16le" | "utf-16be" => self.bytes_value.len().is_multiple_of(2),
+            _ => true
+        };
+
+        if !is_conforming {
+            return Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("charset", charset)
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value decodes to at most `max` bytes, without decoding it.
+    ///
+    /// The decoded size is computed from the encoded length and padding, so oversized payloads are
+    /// rejected before any allocation.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, has an impossible base64 length, or decodes to more than `max` bytes.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_b64_max_bytes(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(len) = b64_decoded_len(&self.string_value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        if let Some(max) = self.max
+            && len > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,58 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// Checks for an entity tag, `"xyzzy"` or weak `W/"xyzzy"`, per RFC 9110.
+fn is_etag(value: &str) -> bool {
+    let opaque = value.strip_prefix("W/").unwrap_or(value);
+
+    opaque.len() >= 2
+        && opaque.starts_with('"')
+        && opaque.ends_with('"')
+        && opaque[1..opaque.len() - 1].bytes().all(|byte| byte == 0x21 || (0x23..=0x7e).contains(&byte) || byte >= 0x80)
+}
+
+impl Validator {
+    /// Configures whether the value is an `If-Match`/`If-None-Match` header, holding `*` or a
+    /// comma-separated list of entity tags, rather than a single entity tag.
+    ///
+    /// # Arguments
+    /// * `is_etag_list` - A boolean indicating if `*` and lists are accepted.
+    pub fn set_as_etag_list(mut self, is_etag_list: bool) -> Self {
+        self.is_etag_list = is_etag_list;
+        self
+    }
+
+    /// Validates that the string value is an entity tag, such as `"33a64df5"` or weak `W/"33a64df5"`,
+    /// within the minimum/maximum length.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, violates the length constraints, or is
+    ///   not an entity tag (or list of them).
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_etag(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = self.validate_string_length();
+        if value.is_some() {
+            return value;
+        }
+
+        let value = self.string_value.trim();
+        let is_valid = match self.is_etag_list {
+            true => value == "*" || value.split(',').all(|tag| is_etag(tag.trim())),
+            false => is_etag(value)
+        };
+
+        if !is_valid {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,94 @@
+use nulls::Null;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::skew::parse_timestamp;
+use crate::{message, Validator};
+
+const MAX_DRIFT: Duration = Duration::from_secs(300);
+
+impl Validator {
+    /// Sets how old an event timestamp may be, e.g. the retention window of the event store.
+    ///
+    /// # Arguments
+    /// * `retention` - The maximum age, measured from now.
+    pub fn set_retention(mut self, retention: Duration) -> Self {
+        self.retention = Some(retention);
+        self
+    }
+
+    /// Reads the timestamp of the previous event in a stream from a named context value, so events
+    /// can be required to arrive in order.
+    ///
+    /// # Arguments
+    /// * `key` - The context value's name, e.g. `last_event_at`.
+    pub fn set_previous_timestamp_from<T>(mut self, key: T) -> Self
+    where T: ToString
+    {
+        self.previous_timestamp_key = Some(key.to_string());
+        self
+    }
+
+    /// Validates that the string value is an event timestamp that is not ahead of now by more than the
+    /// maximum skew, not older than the retention window, and not earlier than the previous event.
+    ///
+    /// Timestamps are Unix seconds, RFC 3339 timestamps or `YYYY-MM-DDTHH:MM:SS` UTC timestamps, as is
+    /// the previous timestamp in the context. The maximum skew bounds clock drift and defaults to
+    /// 5 minutes; the drift is reported in the `drift` arg and the age in the `age` arg, in seconds.
+    /// An event at the same second as the previous one is in order.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, too far in the future, older
+    ///   than the retention window, or earlier than the previous event.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_event_timestamp(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(timestamp) = parse_timestamp(value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let now = self.now();
+        let drift = timestamp.saturating_sub(now);
+        let max_drift = self.max_skew.unwrap_or(MAX_DRIFT).as_secs() as i64;
+
+        if drift > max_drift {
+            return Null::Value(message::new(format!("{}-future", self.field))
+                .set_args("drift", drift.to_string())
+                .set_args("max", max_drift.to_string())
+                .build());
+        }
+
+        if let Some(retention) = self.retention
+            && now.saturating_sub(timestamp) > retention.as_secs() as i64
+        {
+            return Null::Value(message::new(format!("{}-too-old", self.field))
+                .set_args("age", now.saturating_sub(timestamp).to_string())
+                .set_args("max", retention.as_secs().to_string())
+                .build());
+        }
+
+        let previous = self.previous_timestamp_key
+            .as_deref()
+            .and_then(|key| self.context.get(key))
+            .and_then(|previous| match previous {
+                Value::String(previous) => parse_timestamp(previous.trim()),
+                previous => previous.as_i64()
+            });
+
+        if let Some(previous) = previous
+            && timestamp < previous
+        {
+            return Null::Value(message::get(format!("{}-out-of-order", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
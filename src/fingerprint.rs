@@ -0,0 +1,48 @@
+use serde_json::{Map, Value};
+
+use crate::Validator;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Normalizes a value for fingerprinting: strings are trimmed, inner whitespace is collapsed and
+/// letters are lowercased, and object keys are sorted.
+fn normalize(value: &Value) -> Value {
+    match value {
+        Value::String(string) => Value::from(string.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()),
+        Value::Array(items) => Value::Array(items.iter().map(normalize).collect()),
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<(&String, &Value)>>();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            Value::Object(entries
+                .into_iter()
+                .map(|(key, value)| (key.clone(), normalize(value)))
+                .collect::<Map<String, Value>>())
+        },
+        value => value.clone()
+    }
+}
+
+/// Returns a stable 64-bit FNV-1a hash, as 16 hex digits, of a value's normalized form.
+///
+/// The hash only depends on the normalized JSON, so it can be stored and compared across processes
+/// and releases.
+pub(crate) fn fingerprint(value: &Value) -> String {
+    let normalized = serde_json::to_string(&normalize(value)).unwrap_or_default();
+    let hash = normalized
+        .bytes()
+        .fold(FNV_OFFSET, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME));
+
+    format!("{:016x}", hash)
+}
+
+impl Validator {
+    /// Returns a normalized hash of the field's value, for detecting duplicate submissions.
+    ///
+    /// Values differing only in surrounding or repeated whitespace, letter case, or object key
+    /// order share a fingerprint.
+    pub fn content_fingerprint(&self) -> String {
+        fingerprint(&self.coerced_value())
+    }
+}
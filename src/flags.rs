@@ -0,0 +1,118 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+const FLAG_KEY_MAX: usize = 64;
+
+/// Checks for a lowercase kebab-case or snake_case identifier, e.g. `new-checkout` or `new_checkout`.
+///
+/// The identifier starts with a letter, and words are separated by single hyphens or single
+/// underscores, but not both.
+fn is_flag_identifier(value: &str) -> bool {
+    let separator = match (value.contains('-'), value.contains('_')) {
+        (true, true) => return false,
+        (true, false) => '-',
+        _ => '_'
+    };
+
+    value.starts_with(|c: char| c.is_ascii_lowercase())
+        && value.split(separator).all(|word| {
+            !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+impl Validator {
+    /// Sets the prefixes flag keys cannot start with, e.g. prefixes owned by the platform itself.
+    ///
+    /// # Arguments
+    /// * `reserved_prefixes` - A slice of prefixes, e.g. `&["sys-", "internal_"]`.
+    pub fn set_reserved_prefixes<T>(mut self, reserved_prefixes: &[T]) -> Self
+    where T: ToString
+    {
+        self.reserved_prefixes = reserved_prefixes.iter().map(|prefix| prefix.to_string()).collect();
+        self
+    }
+
+    /// Validates that the string value is a feature flag key: a lowercase kebab-case or snake_case
+    /// identifier within the length bounds that does not start with a reserved prefix.
+    ///
+    /// Lengths default to at most 64 characters. The reserved prefix is reported in the `prefix` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too short or long, not a kebab-case or
+    ///   snake_case identifier, or starts with a reserved prefix.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_flag_key(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(min) = self.min
+            && value.len() < min
+        {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        let max = self.max.unwrap_or(FLAG_KEY_MAX);
+
+        if value.len() > max {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if !is_flag_identifier(value) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if let Some(prefix) = self.reserved_prefixes.iter().find(|prefix| value.starts_with(prefix.as_str())) {
+            return Null::Value(message::new(format!("{}-reserved-prefix", self.field))
+                .set_args("prefix", prefix.as_str())
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value is a variant of an experiment or multivariate flag, such as
+    /// `control` or `treatment-b`, and one of the option list when one is set.
+    ///
+    /// Variants follow the same kebab-case or snake_case rules as flag keys. An unknown variant
+    /// reports the option list in the `options` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a kebab-case or snake_case identifier,
+    ///   or not one of the option list.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_flag_variant(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if !is_flag_identifier(value) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if let Some(variants) = &self.option_list_string
+            && !variants.iter().any(|variant| variant == value)
+        {
+            return Null::Value(message::new(format!("{}-variant", self.field))
+                .set_args("options", self.format_options(variants))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,1003 @@
+use nulls::Null;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+use crate::{fingerprint, message, Context, DuplicatePolicy, Error, Message, ValidationCache, Validator};
+
+/// The kind of value a `Rule` validates, selecting the `validate_*` method it runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RuleKind {
+    /// Runs `validate_string`.
+    #[default]
+    String,
+    /// Runs `validate_name`.
+    #[cfg(feature = "regex")]
+    Name,
+    /// Runs `validate_email`.
+    #[cfg(feature = "email")]
+    Email,
+    /// Runs `validate_i64`, accepting JSON integers and numeric strings.
+    Int,
+    /// Runs `validate_f64`, accepting JSON numbers and numeric strings.
+    Float,
+    /// Runs `validate_list_options`.
+    Options
+}
+
+impl RuleKind {
+    /// Looks up a rule kind by its definition name: `string`, `name`, `email`, `int`, `float` or `options`.
+    ///
+    /// # Arguments
+    /// * `name` - The kind's name, as used in rule definitions.
+    pub fn from_name(name: &str) -> Option<RuleKind> {
+        match name {
+            "string" => Some(RuleKind::String),
+            #[cfg(feature = "regex")]
+            "name" => Some(RuleKind::Name),
+            #[cfg(feature = "email")]
+            "email" => Some(RuleKind::Email),
+            "int" => Some(RuleKind::Int),
+            "float" => Some(RuleKind::Float),
+            "options" => Some(RuleKind::Options),
+            _ => None
+        }
+    }
+}
+
+/// A reusable, field-independent validation rule, e.g. `email().required()` or `int().min(18)`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Rule {
+    pub kind: RuleKind,
+    pub is_required: bool,
+    pub is_case_sensitive: bool,
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    pub fmin: Option<f64>,
+    pub fmax: Option<f64>,
+    pub options: Option<RuleOptions>,
+    pub pattern: Option<&'static str>,
+    pub after: Option<&'static str>,
+    pub before: Option<&'static str>,
+    pub default: Option<Value>,
+    pub min_occurrences: Option<usize>,
+    pub max_occurrences: Option<usize>,
+    pub duplicate_policy: Option<DuplicatePolicy>
+}
+
+/// The options of a `one_of` rule, either borrowed from a `static` slice or owned.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleOptions {
+    Static(&'static [&'static str]),
+    Owned(Vec<String>)
+}
+
+impl RuleOptions {
+    /// Iterates over the options.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let (statics, owned) = match self {
+            RuleOptions::Static(options) => (*options, &[][..]),
+            RuleOptions::Owned(options) => (&[][..], options.as_slice())
+        };
+
+        statics.iter().copied().chain(owned.iter().map(String::as_str))
+    }
+
+    /// Copies the options into an owned list.
+    pub fn to_vec(&self) -> Vec<String> {
+        self.iter().map(String::from).collect()
+    }
+}
+
+/// Creates a rule validated with `validate_string`.
+pub const fn string() -> Rule {
+    Rule::new(RuleKind::String)
+}
+
+/// Creates a rule validated with `validate_name`.
+#[cfg(feature = "regex")]
+pub const fn name() -> Rule {
+    Rule::new(RuleKind::Name)
+}
+
+/// Creates a rule validated with `validate_email`.
+#[cfg(feature = "email")]
+pub const fn email() -> Rule {
+    Rule::new(RuleKind::Email)
+}
+
+/// Creates a rule validated with `validate_i64`.
+pub const fn int() -> Rule {
+    Rule::new(RuleKind::Int)
+}
+
+/// Creates a rule validated with `validate_f64`.
+pub const fn float() -> Rule {
+    Rule::new(RuleKind::Float)
+}
+
+/// Creates a rule validated with `validate_list_options` against the given options.
+///
+/// # Arguments
+/// * `options` - A slice of items convertible to strings.
+pub fn one_of<T>(options: &[T]) -> Rule
+where T: ToString
+{
+    Rule {
+        options: Some(RuleOptions::Owned(options.iter().map(|value| value.to_string()).collect())),
+        ..Rule::new(RuleKind::Options)
+    }
+}
+
+/// Creates a rule validated with `validate_list_options` against a `static` list of options.
+///
+/// Unlike `one_of`, this can be used in `const` and `static` rule declarations.
+///
+/// # Arguments
+/// * `options` - The static options.
+pub const fn one_of_static(options: &'static [&'static str]) -> Rule {
+    Rule::with_options(RuleKind::Options, Some(RuleOptions::Static(options)))
+}
+
+impl Rule {
+    /// Creates a rule of the given kind.
+    ///
+    /// # Arguments
+    /// * `kind` - The kind of value the rule validates.
+    pub const fn new(kind: RuleKind) -> Self {
+        Rule::with_options(kind, None)
+    }
+
+    /// Creates a rule of the given kind and options, without moving out of a base rule so it stays `const`.
+    const fn with_options(kind: RuleKind, options: Option<RuleOptions>) -> Self {
+        Rule {
+            kind,
+            is_required: false,
+            is_case_sensitive: false,
+            min: None,
+            max: None,
+            fmin: None,
+            fmax: None,
+            options,
+            pattern: None,
+            after: None,
+            before: None,
+            default: None,
+            min_occurrences: None,
+            max_occurrences: None,
+            duplicate_policy: None
+        }
+    }
+
+    /// Marks the field as required.
+    pub const fn required(mut self) -> Self {
+        self.is_required = true;
+        self
+    }
+
+    /// Makes option matching case-sensitive.
+    pub const fn case_sensitive(mut self) -> Self {
+        self.is_case_sensitive = true;
+        self
+    }
+
+    /// Sets the minimum length, or the minimum value for `int()` rules.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum allowed.
+    pub const fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum length, or the maximum value for `int()` rules.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum allowed.
+    pub const fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the minimum value for `float()` rules.
+    ///
+    /// # Arguments
+    /// * `fmin` - The minimum value allowed.
+    pub const fn fmin(mut self, fmin: f64) -> Self {
+        self.fmin = Some(fmin);
+        self
+    }
+
+    /// Sets the maximum value for `float()` rules.
+    ///
+    /// # Arguments
+    /// * `fmax` - The maximum value allowed.
+    pub const fn fmax(mut self, fmax: f64) -> Self {
+        self.fmax = Some(fmax);
+        self
+    }
+
+    /// Sets the regular expression string values must match, checked after the rule's kind.
+    ///
+    /// # Arguments
+    /// * `pattern` - The static pattern, e.g. `^[a-z0-9_]+$`.
+    #[cfg(feature = "regex")]
+    pub const fn pattern(mut self, pattern: &'static str) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Requires the value to be a date or timestamp later than another field's in the same payload.
+    ///
+    /// # Arguments
+    /// * `field` - The other field, e.g. `created_at`.
+    pub const fn must_be_after(mut self, field: &'static str) -> Self {
+        self.after = Some(field);
+        self
+    }
+
+    /// Requires the value to be a date or timestamp earlier than another field's in the same payload.
+    ///
+    /// # Arguments
+    /// * `field` - The other field, e.g. `expires_at`.
+    pub const fn must_be_before(mut self, field: &'static str) -> Self {
+        self.before = Some(field);
+        self
+    }
+
+    /// Accepts the field as an array of repeated values, e.g. from `tag=a&tag=b`, occurring `min` to
+    /// `max` times. Each value is validated against the rule.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum number of occurrences.
+    /// * `max` - The maximum number of occurrences.
+    pub const fn occurrences(mut self, min: usize, max: usize) -> Self {
+        self.min_occurrences = Some(min);
+        self.max_occurrences = Some(max);
+        self
+    }
+
+    /// Accepts an array of repeated values for a single-valued field, keeping one according to the policy.
+    ///
+    /// # Arguments
+    /// * `policy` - Whether to keep the first or last value, or reject the field.
+    pub const fn duplicates(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = Some(policy);
+        self
+    }
+
+    /// Checks whether the rule accepts arrays of repeated values.
+    const fn is_repeatable(&self) -> bool {
+        self.min_occurrences.is_some() || self.max_occurrences.is_some() || self.duplicate_policy.is_some()
+    }
+
+    /// Picks the repeated values that are validated and kept, according to the duplicate policy.
+    fn kept<'a>(&self, items: &'a [Value]) -> &'a [Value] {
+        match self.duplicate_policy {
+            Some(DuplicatePolicy::First) => &items[..items.len().min(1)],
+            Some(DuplicatePolicy::Last) => &items[items.len().saturating_sub(1)..],
+            _ => items
+        }
+    }
+
+    /// Sets the value used when the field is absent; absent fields with a default always pass.
+    ///
+    /// # Arguments
+    /// * `value` - The default value, convertible to a JSON value.
+    pub fn default_value<T>(mut self, value: T) -> Self
+    where T: Into<Value>
+    {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Builds a `Validator` for the field, configured with this rule's constraints.
+    ///
+    /// The validator is always marked as required, since rules only run validators on present values.
+    ///
+    /// # Arguments
+    /// * `field` - The field name, used for the i18n keys.
+    pub fn validator<T>(&self, field: T) -> Validator
+    where T: ToString
+    {
+        Validator {
+            min: self.min,
+            max: self.max,
+            fmin: self.fmin,
+            fmax: self.fmax,
+            option_list_string: self.options.as_ref().map(RuleOptions::to_vec),
+            is_case_sensitive: self.is_case_sensitive,
+            default_value: self.default.clone(),
+            ..Validator::new(field).set_as_required(true)
+        }
+    }
+
+    /// Validates a JSON value against this rule.
+    ///
+    /// Missing, `null` and empty string values only fail when the rule is required and has no default.
+    /// Arrays of repeated values are accepted when the rule sets `occurrences` or `duplicates`.
+    ///
+    /// # Arguments
+    /// * `field` - The field name, used for the i18n keys.
+    /// * `value` - The field's value, if present.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but missing, has the wrong type, or fails the rule.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate<T>(&self, field: T, value: Option<&Value>) -> Null<String>
+    where T: ToString
+    {
+        message::render(self.check(field, value))
+    }
+
+    /// Validates a JSON value like `validate`, returning the unrendered message behind an error.
+    pub(crate) fn check<T>(&self, field: T, value: Option<&Value>) -> Null<Message>
+    where T: ToString
+    {
+        let field = field.to_string();
+        let value = value.map(sanitize);
+
+        let Some(value) = value.as_ref().filter(|value| !is_absent(Some(value))) else {
+            return match self.is_required && self.default.is_none() {
+                true => Null::Value(message::key(format!("{}-empty", field))),
+                false => Null::Undefined
+            };
+        };
+
+        if let Value::Array(items) = value
+            && self.is_repeatable()
+        {
+            let occurrences = items
+                .iter()
+                .map(|item| match item {
+                    Value::String(string) => string.clone(),
+                    item => item.to_string()
+                })
+                .collect::<Vec<String>>();
+
+            let mut validator = self.validator(&field)
+                .set_occurrences(&occurrences)
+                .set_as_required(self.is_required && self.default.is_none());
+            validator.min_occurrences = self.min_occurrences;
+            validator.max_occurrences = self.max_occurrences;
+            validator.duplicate_policy = self.duplicate_policy;
+
+            if let (Null::Value(error), _) = validator.parse_occurrences() {
+                return Null::Value(error);
+            }
+
+            return self.kept(items)
+                .iter()
+                .map(|item| self.validate_value(&field, &sanitize(item)))
+                .find(Null::is_some)
+                .unwrap_or(Null::Undefined);
+        }
+
+        self.validate_value(&field, value)
+    }
+
+    /// Validates a present, sanitized value against the rule's kind and pattern.
+    fn validate_value(&self, field: &str, value: &Value) -> Null<Message> {
+        let validator = self.validator(field);
+        let invalid = || Null::Value(message::key(format!("{}-invalid", field)));
+
+        match self.kind {
+            RuleKind::Int => match value.as_i64().or_else(|| value.as_str().and_then(|s| s.trim().parse().ok())) {
+                Some(int) => validator.set_i64_value(&Null::Value(int)).check_i64(),
+                None => invalid()
+            },
+            RuleKind::Float => match value.as_f64().or_else(|| value.as_str().and_then(|s| s.trim().parse().ok())) {
+                Some(float) => validator.set_f64_value(&Null::Value(float)).check_f64(),
+                None => invalid()
+            },
+            kind => {
+                let string = match value {
+                    Value::String(string) => string.clone(),
+                    Value::Number(number) => number.to_string(),
+                    Value::Bool(boolean) => boolean.to_string(),
+                    _ => return invalid()
+                };
+
+                let validator = validator.set_string_value(&Null::Value(string));
+
+                let outcome = match kind {
+                    #[cfg(feature = "regex")]
+                    RuleKind::Name => validator.check_name(),
+                    #[cfg(feature = "email")]
+                    RuleKind::Email => validator.check_email(),
+                    RuleKind::Options => validator.check_list_options(),
+                    _ => validator.check_string()
+                };
+
+                #[cfg(feature = "regex")]
+                if !outcome.is_some()
+                    && let Some(pattern) = self.pattern
+                    && !regex::Regex::new(pattern).is_ok_and(|re| re.is_match(&validator.string_value))
+                {
+                    return invalid();
+                }
+
+                outcome
+            }
+        }
+    }
+}
+
+impl Rule {
+    /// Builds a rule from a JSON definition, e.g. `{"type": "int", "required": true, "min": 18}`.
+    ///
+    /// Supported keys are `type` (defaulting to `string`), `required`, `case_sensitive`, `min`,
+    /// `max`, `fmin`, `fmax`, `options` and `default`. Patterns can only be set on static rules.
+    ///
+    /// # Arguments
+    /// * `definition` - The rule definition object.
+    ///
+    /// # Returns
+    /// * `Some(Rule)` - The rule described by the definition.
+    /// * `None` - If the definition is not an object, names an unknown type, or has a mistyped key.
+    pub fn from_definition(definition: &Value) -> Option<Rule> {
+        let definition = definition.as_object()?;
+        let flag = |key: &str| match definition.get(key) {
+            None => Some(false),
+            Some(value) => value.as_bool()
+        };
+        let count = |key: &str| match definition.get(key) {
+            None => Some(None),
+            Some(value) => value.as_u64().and_then(|value| usize::try_from(value).ok()).map(Some)
+        };
+        let number = |key: &str| match definition.get(key) {
+            None => Some(None),
+            Some(value) => value.as_f64().map(Some)
+        };
+
+        let kind = match definition.get("type") {
+            None => RuleKind::String,
+            Some(kind) => RuleKind::from_name(kind.as_str()?)?
+        };
+
+        let options = match definition.get("options") {
+            None => None,
+            Some(options) => Some(RuleOptions::Owned(options
+                .as_array()?
+                .iter()
+                .map(|option| option.as_str().map(String::from))
+                .collect::<Option<Vec<String>>>()?))
+        };
+
+        let mut rule = Rule::with_options(kind, options);
+
+        rule.is_required = flag("required")?;
+        rule.is_case_sensitive = flag("case_sensitive")?;
+        rule.min = count("min")?;
+        rule.max = count("max")?;
+        rule.fmin = number("fmin")?;
+        rule.fmax = number("fmax")?;
+        rule.default = definition.get("default").cloned();
+
+        Some(rule)
+    }
+
+    /// Describes each setting that differs from another rule, e.g. `max 20 → 32`.
+    ///
+    /// # Arguments
+    /// * `other` - The rule to compare against.
+    pub fn changes(&self, other: &Rule) -> Vec<String> {
+        fn show<T: fmt::Debug>(value: &Option<T>) -> String {
+            match value {
+                Some(value) => format!("{:?}", value),
+                None => "none".to_string()
+            }
+        }
+
+        let options = |rule: &Rule| rule.options.as_ref().map(RuleOptions::to_vec);
+        let settings = [
+            ("type", format!("{:?}", self.kind), format!("{:?}", other.kind)),
+            ("required", self.is_required.to_string(), other.is_required.to_string()),
+            ("case_sensitive", self.is_case_sensitive.to_string(), other.is_case_sensitive.to_string()),
+            ("min", show(&self.min), show(&other.min)),
+            ("max", show(&self.max), show(&other.max)),
+            ("fmin", show(&self.fmin), show(&other.fmin)),
+            ("fmax", show(&self.fmax), show(&other.fmax)),
+            ("options", show(&options(self)), show(&options(other))),
+            ("pattern", show(&self.pattern), show(&other.pattern)),
+            ("after", show(&self.after), show(&other.after)),
+            ("before", show(&self.before), show(&other.before)),
+            ("default", show(&self.default), show(&other.default)),
+            ("min_occurrences", show(&self.min_occurrences), show(&other.min_occurrences)),
+            ("max_occurrences", show(&self.max_occurrences), show(&other.max_occurrences)),
+            ("duplicates", show(&self.duplicate_policy), show(&other.duplicate_policy))
+        ];
+
+        settings
+            .into_iter()
+            .filter(|(_, from, to)| from != to)
+            .map(|(name, from, to)| format!("{} {} → {}", name, from, to))
+            .collect()
+    }
+
+    /// Returns a hash of the rule's configuration, for use as a `ValidationCache` key.
+    ///
+    /// Two rules with the same kind, constraints, options and default share a fingerprint.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        format!("{:?}", self).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Converts a JSON value into its sanitized, coerced form for this rule.
+    ///
+    /// Strings are trimmed, numbers are parsed, options take their configured casing and email
+    /// domains are lowercased. Absent values fall back to the rule's default. Repeated values are
+    /// coerced one by one, or reduced to the one kept by the duplicate policy.
+    ///
+    /// # Arguments
+    /// * `value` - The field's value, if present.
+    ///
+    /// # Returns
+    /// * `Some(Value)` - The coerced value or default.
+    /// * `None` - If the value is absent without a default, or cannot be coerced.
+    pub fn coerce(&self, value: Option<&Value>) -> Option<Value> {
+        let value = value.map(sanitize);
+
+        let Some(value) = value.filter(|value| !is_absent(Some(value))) else {
+            return self.default.clone();
+        };
+
+        if let Value::Array(items) = &value
+            && self.is_repeatable()
+        {
+            let values = self.kept(items)
+                .iter()
+                .filter_map(|item| self.coerce(Some(item)))
+                .collect::<Vec<Value>>();
+
+            return match self.duplicate_policy {
+                Some(DuplicatePolicy::First | DuplicatePolicy::Last) => values.into_iter().next(),
+                _ => Some(Value::Array(values))
+            };
+        }
+
+        match self.kind {
+            RuleKind::Int => value.as_i64()
+                .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                .map(Value::from),
+            RuleKind::Float => value.as_f64()
+                .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                .and_then(Number::from_f64)
+                .map(Value::Number),
+            #[cfg(feature = "email")]
+            RuleKind::Email => value.as_str().map(|email| match email.rsplit_once('@') {
+                Some((local, domain)) => Value::from(format!("{}@{}", local, domain.to_lowercase())),
+                None => Value::from(email)
+            }),
+            RuleKind::Options => {
+                let string = match &value {
+                    Value::String(string) => string.clone(),
+                    value => value.to_string()
+                };
+
+                self.options
+                    .iter()
+                    .flat_map(RuleOptions::iter)
+                    .find(|option| match self.is_case_sensitive {
+                        true => *option == string,
+                        false => option.to_lowercase() == string.to_lowercase()
+                    })
+                    .map(Value::from)
+            },
+            _ => match value {
+                Value::Array(_) | Value::Object(_) => None,
+                Value::String(string) => Some(Value::String(string)),
+                value => Some(Value::from(value.to_string()))
+            }
+        }
+    }
+}
+
+/// The aggregated outcome of validating a payload against a `ValidatorSet`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// Error messages keyed by field name.
+    pub errors: Map<String, Value>,
+    /// Default values applied to absent fields, keyed by field name.
+    pub defaults: Map<String, Value>,
+    /// Whether validation stopped early because the error budget was spent.
+    pub truncated: bool,
+    /// The unrendered i18n key and args behind each error, for rendering in other locales.
+    pub messages: HashMap<String, Message>,
+    /// A normalized hash of the submitted field values, for detecting duplicate submissions.
+    pub fingerprint: String
+}
+
+impl Report {
+    /// Checks whether every field passed validation.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Renders the error map in several locales at once, e.g. `{"email": {"en": "…", "ja": "…"}}`.
+    ///
+    /// Messages are rendered through the `Translator` installed with `set_translator`. Errors without
+    /// a message keep their default-locale text in every locale.
+    ///
+    /// # Arguments
+    /// * `locales` - The locales to render, e.g. `&["en", "ja"]`.
+    pub fn render_errors<T>(&self, locales: &[T]) -> Map<String, Value>
+    where T: AsRef<str>
+    {
+        self.errors
+            .iter()
+            .map(|(field, error)| {
+                let rendered = locales
+                    .iter()
+                    .map(|locale| {
+                        let message = match self.messages.get(field) {
+                            Some(message) => message.render_in(locale.as_ref()),
+                            None => error.as_str().unwrap_or_default().to_string()
+                        };
+
+                        (locale.as_ref().to_string(), Value::from(message))
+                    })
+                    .collect::<Map<String, Value>>();
+
+                (field.clone(), Value::Object(rendered))
+            })
+            .collect()
+    }
+}
+
+/// A whole form declared at once as named fields and their rules.
+#[derive(Clone, Debug, Default)]
+pub struct ValidatorSet {
+    pub fields: Vec<(String, Rule)>,
+    pub max_errors: Option<usize>,
+    pub cache: Option<ValidationCache>,
+    pub version: Option<String>,
+    pub shadow: Option<Shadow>
+}
+
+/// A stricter rule set evaluated alongside the active one, reporting would-be failures to a callback.
+#[derive(Clone)]
+pub struct Shadow {
+    pub set: Box<ValidatorSet>,
+    pub on_failure: Arc<dyn Fn(&Report) + Send + Sync>
+}
+
+impl fmt::Debug for Shadow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shadow").field("set", &self.set).finish_non_exhaustive()
+    }
+}
+
+/// A difference between two versions of a `ValidatorSet`, as returned by `ValidatorSet::diff`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleChange {
+    /// A field that only the newer set validates.
+    Added { field: String, rule: Rule },
+    /// A field that only the older set validates.
+    Removed { field: String, rule: Rule },
+    /// A field whose rule changed.
+    Changed { field: String, from: Rule, to: Rule }
+}
+
+impl fmt::Display for RuleChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleChange::Added { field, .. } => write!(f, "added `{}`", field),
+            RuleChange::Removed { field, .. } => write!(f, "removed `{}`", field),
+            RuleChange::Changed { field, from, to } => {
+                write!(f, "changed `{}`: {}", field, from.changes(to).join(", "))
+            }
+        }
+    }
+}
+
+/// Creates an empty `ValidatorSet`.
+pub fn form() -> ValidatorSet {
+    ValidatorSet::new()
+}
+
+/// Declares a `ValidatorSet` from `field => rule` pairs.
+///
+/// ```ignore
+/// let signup = form! {
+///     "email" => email().required(),
+///     "age" => int().min(18)
+/// };
+/// ```
+#[macro_export]
+macro_rules! form {
+    ($($field:expr => $rule:expr),* $(,)?) => {
+        $crate::form()$(.field($field, $rule))*
+    };
+}
+
+impl ValidatorSet {
+    /// Creates an empty `ValidatorSet`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field and its rule.
+    ///
+    /// # Arguments
+    /// * `field` - The field name, matching the payload key.
+    /// * `rule` - The rule to validate the field with.
+    pub fn field<T>(mut self, field: T, rule: Rule) -> Self
+    where T: ToString
+    {
+        self.fields.push((field.to_string(), rule));
+        self
+    }
+
+    /// Builds a form from a JSON definition mapping field names to rule definitions.
+    ///
+    /// # Arguments
+    /// * `definition` - An object such as `{"email": {"type": "email", "required": true}}`.
+    ///
+    /// # Returns
+    /// * `Ok(ValidatorSet)` - The form described by the definition.
+    /// * `Err(Error)` - The first field whose rule definition is invalid, with its `{field}-invalid` message.
+    pub fn from_definition(definition: &Value) -> Result<ValidatorSet, Error> {
+        let invalid = |field: &str| Error {
+            field: field.to_string(),
+            message: message::get(format!("{}-invalid", field))
+        };
+
+        let Some(fields) = definition.as_object() else {
+            return Err(invalid("form"));
+        };
+
+        fields.iter().try_fold(ValidatorSet::new(), |set, (field, rule)| match Rule::from_definition(rule) {
+            Some(rule) => Ok(set.field(field, rule)),
+            None => Err(invalid(field))
+        })
+    }
+
+    /// Sets the maximum number of field errors to collect before stopping.
+    ///
+    /// # Arguments
+    /// * `max_errors` - The maximum number of errors to report.
+    pub fn set_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// Tags the set with a version, e.g. `v2`, for registering several versions side by side.
+    ///
+    /// # Arguments
+    /// * `version` - The version tag.
+    pub fn set_version<T>(mut self, version: T) -> Self
+    where T: ToString
+    {
+        self.version = Some(version.to_string());
+        self
+    }
+
+    /// Describes what changed from this set to a newer one: added, removed and changed fields.
+    ///
+    /// # Arguments
+    /// * `newer` - The set to compare against.
+    pub fn diff(&self, newer: &ValidatorSet) -> Vec<RuleChange> {
+        let find = |set: &ValidatorSet, field: &str| set.fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, rule)| rule.clone());
+
+        let mut changes = Vec::new();
+
+        for (field, rule) in &self.fields {
+            match find(newer, field) {
+                None => changes.push(RuleChange::Removed { field: field.clone(), rule: rule.clone() }),
+                Some(to) if to != *rule => changes.push(RuleChange::Changed {
+                    field: field.clone(),
+                    from: rule.clone(),
+                    to
+                }),
+                Some(_) => {}
+            }
+        }
+
+        for (field, rule) in &newer.fields {
+            if find(self, field).is_none() {
+                changes.push(RuleChange::Added { field: field.clone(), rule: rule.clone() });
+            }
+        }
+
+        changes
+    }
+
+    /// Evaluates a stricter rule set in shadow mode alongside this one.
+    ///
+    /// After each `validate`, fields that pass this set but fail the shadow set are reported to
+    /// `on_failure`, even when this set's report stopped early at `max_errors`. The shadow set never
+    /// changes the returned report, so the impact of tightening rules can be measured before enforcing them.
+    ///
+    /// # Arguments
+    /// * `shadow` - The candidate rule set.
+    /// * `on_failure` - Receives a report holding only the would-be new failures.
+    pub fn set_shadow<F>(mut self, shadow: ValidatorSet, on_failure: F) -> Self
+    where F: Fn(&Report) + Send + Sync + 'static
+    {
+        self.shadow = Some(Shadow {
+            set: Box::new(shadow),
+            on_failure: Arc::new(on_failure)
+        });
+        self
+    }
+
+    /// Sets a cache for field outcomes, so repeated identical submissions skip revalidation.
+    ///
+    /// # Arguments
+    /// * `cache` - The cache to share, keyed by field, rule fingerprint and value.
+    pub fn set_cache(mut self, cache: ValidationCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Validates a JSON object against every field's rule.
+    ///
+    /// Non-object payloads are treated as if every field were missing. Once `max_errors` errors are
+    /// collected, validation stops at the next failing field and the report is marked `truncated`;
+    /// defaults of absent fields are still recorded for every field.
+    ///
+    /// # Arguments
+    /// * `payload` - The JSON object to validate.
+    pub fn validate(&self, payload: &Value) -> Report {
+        let mut report = Report::default();
+
+        for (field, rule) in &self.fields {
+            let value = payload.get(field.as_str());
+
+            if let Some(default) = &rule.default
+                && is_absent(value)
+            {
+                report.defaults.insert(field.clone(), default.clone());
+            }
+
+            // Once the budget is spent, later fields still record their defaults but are not validated
+            if report.truncated {
+                continue;
+            }
+
+            if let Null::Value(message) = self.validate_field(field, rule, value, payload) {
+                if self.max_errors.is_some_and(|max| report.errors.len() >= max) {
+                    report.truncated = true;
+                    continue;
+                }
+
+                report.errors.insert(field.clone(), message.render().into());
+                report.messages.insert(field.clone(), message);
+            }
+        }
+
+        report.fingerprint = self.fingerprint(payload);
+
+        if let Some(shadow) = &self.shadow {
+            // A truncated report never validated its later fields, so compare against a full run instead
+            let rejected = match report.truncated {
+                true => self.fields
+                    .iter()
+                    .filter(|(field, rule)| self.validate_field(field, rule, payload.get(field.as_str()), payload).is_some())
+                    .map(|(field, _)| field.clone())
+                    .collect::<HashSet<String>>(),
+                false => report.errors.keys().cloned().collect()
+            };
+
+            let mut would_fail = shadow.set.validate(payload);
+            would_fail.errors.retain(|field, _| !rejected.contains(field));
+            would_fail.messages.retain(|field, _| would_fail.errors.contains_key(field));
+            would_fail.defaults.retain(|field, _| would_fail.errors.contains_key(field));
+
+            if !would_fail.is_valid() {
+                (shadow.on_failure)(&would_fail);
+            }
+        }
+
+        report
+    }
+
+    /// Returns the sanitized, coerced values of every field that passes validation.
+    ///
+    /// Failing fields and absent fields without a default are left out.
+    ///
+    /// # Arguments
+    /// * `payload` - The JSON object to read the values from.
+    pub fn values(&self, payload: &Value) -> Map<String, Value> {
+        let mut values = Map::new();
+
+        for (field, rule) in &self.fields {
+            let value = payload.get(field.as_str());
+
+            if self.validate_field(field, rule, value, payload).is_some() {
+                continue;
+            }
+
+            if let Some(value) = rule.coerce(value) {
+                values.insert(field.clone(), value);
+            }
+        }
+
+        values
+    }
+
+    /// Returns a normalized hash of the payload's field values, ignoring keys the set does not declare.
+    ///
+    /// Values are coerced by their rules first, so `" 42 "` and `42` fingerprint alike for `int()` fields.
+    ///
+    /// # Arguments
+    /// * `payload` - The JSON object to fingerprint.
+    pub fn fingerprint(&self, payload: &Value) -> String {
+        let values = self.fields
+            .iter()
+            .map(|(field, rule)| {
+                let value = payload.get(field.as_str());
+                let value = rule.coerce(value).or_else(|| value.cloned()).unwrap_or_default();
+
+                (field.clone(), value)
+            })
+            .collect::<Map<String, Value>>();
+
+        fingerprint::fingerprint(&Value::Object(values))
+    }
+
+    /// Validates a single field, going through the cache when one is set.
+    ///
+    /// Temporal order against other fields depends on the whole payload, so it is checked outside the cache.
+    fn validate_field(&self, field: &str, rule: &Rule, value: Option<&Value>, payload: &Value) -> Null<Message> {
+        let outcome = match &self.cache {
+            Some(cache) => cache.get_or_check(
+                (field, rule.fingerprint()),
+                value.map(|value| value.to_string()),
+                || rule.check(field, value)
+            ),
+            None => rule.check(field, value)
+        };
+
+        if outcome.is_some() || (rule.after.is_none() && rule.before.is_none()) {
+            return outcome;
+        }
+
+        let Some(value) = value.and_then(Value::as_str).filter(|value| !value.trim().is_empty()) else {
+            return outcome;
+        };
+
+        let mut validator = Validator::new(field)
+            .set_string_value(&Null::Value(value.to_string()))
+            .set_context(&Context::from_payload(payload));
+
+        validator.must_be_after = rule.after.map(String::from);
+        validator.must_be_before = rule.before.map(String::from);
+        validator.check_temporal_order()
+    }
+
+    /// Validates a serializable struct against every field's rule.
+    ///
+    /// # Arguments
+    /// * `payload` - The struct to validate, serialized through `serde_json`.
+    pub fn validate_struct<T>(&self, payload: &T) -> Report
+    where T: Serialize
+    {
+        self.validate(&serde_json::to_value(payload).unwrap_or_default())
+    }
+}
+
+/// Checks whether a payload value counts as absent: missing, `null`, or an empty string.
+pub(crate) fn is_absent(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => true,
+        Some(Value::String(string)) => string.is_empty(),
+        Some(_) => false
+    }
+}
+
+/// Sanitizes a payload value before validation and coercion by trimming strings.
+pub(crate) fn sanitize(value: &Value) -> Value {
+    match value {
+        Value::String(string) => Value::String(string.trim().to_string()),
+        value => value.clone()
+    }
+}
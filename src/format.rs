@@ -0,0 +1,516 @@
+use crate::{message, Validator};
+
+/// Returns the language part of a locale tag, e.g. `pt` for `pt-BR`.
+fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Returns the digit grouping and decimal separators of a locale.
+fn separators(locale: &str) -> (&'static str, &'static str) {
+    match language(locale).as_str() {
+        "de" | "es" | "id" | "it" | "nl" | "pt" | "tr" | "da" | "el" | "ro" | "hr" | "sl" | "sr" | "vi" => (".", ","),
+        "fr" => ("\u{202f}", ","),
+        "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "hu" | "bg" | "lt" | "lv" | "et" => ("\u{a0}", ","),
+        _ => (",", ".")
+    }
+}
+
+/// Formats a number with a locale's digit grouping and decimal separator, e.g. `1,000,000`.
+///
+/// # Arguments
+/// * `value` - The number to format.
+/// * `locale` - The locale, e.g. `en` or `de-DE`.
+pub fn format_number(value: f64, locale: &str) -> String {
+    let raw = match value.fract() == 0.0 && value.abs() < 1e15 {
+        true => format!("{}", value as i64),
+        false => value.to_string()
+    };
+
+    group_digits(&raw, locale).unwrap_or(raw)
+}
+
+/// Groups the digits of a plain decimal string such as `-1234567.5`, without going through `f64`.
+///
+/// # Returns
+/// * `Some(String)` - The grouped number.
+/// * `None` - If the string is not a plain decimal number.
+pub(crate) fn group_digits(raw: &str, locale: &str) -> Option<String> {
+    let (group, decimal) = separators(locale);
+
+    let (sign, raw) = match raw.strip_prefix('-') {
+        Some(raw) => ("-", raw),
+        None => ("", raw)
+    };
+
+    let (integer, fraction) = match raw.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (raw, None)
+    };
+
+    let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+    if !is_digits(integer) || fraction.is_some_and(|fraction| !is_digits(fraction)) {
+        return None;
+    }
+
+    let mut grouped = String::new();
+
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(3) {
+            grouped.push_str(group);
+        }
+
+        grouped.push(digit);
+    }
+
+    Some(match fraction {
+        Some(fraction) => format!("{}{}{}{}", sign, grouped, decimal, fraction),
+        None => format!("{}{}", sign, grouped)
+    })
+}
+
+/// Returns the CLDR plural category of a number in a locale: `one`, `few`, `many` or `other`.
+fn plural_category(value: f64, locale: &str) -> &'static str {
+    if value.fract() != 0.0 {
+        return "other";
+    }
+
+    let n = value.abs() as u64;
+
+    match language(locale).as_str() {
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "lo" | "my" => "other",
+        "fr" | "pt" if n <= 1 => "one",
+        "fr" | "pt" => "other",
+        "ru" | "uk" | "be" | "sr" | "hr" | "bs" => match (n % 10, n % 100) {
+            (1, rem) if rem != 11 => "one",
+            (2..=4, rem) if !(12..=14).contains(&rem) => "few",
+            _ => "many"
+        },
+        "pl" => match (n, n % 10, n % 100) {
+            (1, _, _) => "one",
+            (_, 2..=4, rem) if !(12..=14).contains(&rem) => "few",
+            _ => "many"
+        },
+        "cs" | "sk" => match n {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other"
+        },
+        _ if n == 1 => "one",
+        _ => "other"
+    }
+}
+
+/// Returns the CLDR ordinal category of a number in English-like locales: `one`, `two`, `few` or `other`.
+fn ordinal_category(value: f64, locale: &str) -> &'static str {
+    let n = value.abs() as u64;
+
+    match language(locale).as_str() {
+        "en" => match (n % 10, n % 100) {
+            (1, rem) if rem != 11 => "one",
+            (2, rem) if rem != 12 => "two",
+            (3, rem) if rem != 13 => "few",
+            _ => "other"
+        },
+        _ => "other"
+    }
+}
+
+/// Checks whether a message pattern uses ICU `number`, `plural`, `select` or `selectordinal` arguments.
+pub(crate) fn is_icu(pattern: &str) -> bool {
+    let compact = pattern.split_whitespace().collect::<String>();
+
+    [",number}", ",number,", ",plural,", ",select,", ",selectordinal,"]
+        .iter()
+        .any(|construct| compact.contains(construct))
+}
+
+/// Formats an ICU MessageFormat pattern with the given args.
+///
+/// Supports `{name}`, `{name, number}` (with `integer` and `percent` styles),
+/// `{name, plural, =0 {…} one {# item} other {# items}}`, `{name, select, a {…} other {…}}`,
+/// `{name, selectordinal, …}` and apostrophe quoting. Unknown args are left as `{name}`.
+///
+/// # Arguments
+/// * `pattern` - The message pattern.
+/// * `locale` - The locale used for digit grouping and plural rules.
+/// * `args` - The message args.
+pub fn format_message(pattern: &str, locale: &str, args: &[(String, String)]) -> String {
+    let chars = pattern.chars().collect::<Vec<char>>();
+
+    render(&chars, locale, args, None)
+}
+
+/// Renders a (sub)message; `hash` is the number `#` stands for inside a plural branch.
+fn render(chars: &[char], locale: &str, args: &[(String, String)], hash: Option<f64>) -> String {
+    let mut output = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\'' if chars.get(i + 1) == Some(&'\'') => {
+                output.push('\'');
+                i += 2;
+            },
+            '\'' if matches!(chars.get(i + 1), Some('{' | '}' | '#')) => {
+                let end = chars[i + 1..].iter().position(|c| *c == '\'').map(|end| i + 1 + end).unwrap_or(chars.len());
+                output.extend(&chars[i + 1..end]);
+                i = end + 1;
+            },
+            '#' if hash.is_some() => {
+                output.push_str(&format_number(hash.unwrap_or_default(), locale));
+                i += 1;
+            },
+            '{' => {
+                let end = closing(chars, i);
+                output.push_str(&argument(&chars[i + 1..end], locale, args));
+                i = end + 1;
+            },
+            c => {
+                output.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns the index of the `}` closing the `{` at `open`, or the end of the pattern.
+fn closing(chars: &[char], open: usize) -> usize {
+    let mut depth = 0;
+
+    for (i, c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return i;
+                }
+            },
+            _ => {}
+        }
+    }
+
+    chars.len()
+}
+
+/// Formats the inside of a `{…}` argument.
+fn argument(chars: &[char], locale: &str, args: &[(String, String)]) -> String {
+    let body = chars.iter().collect::<String>();
+    let mut parts = body.splitn(3, ',');
+    let name = parts.next().unwrap_or_default().trim();
+    let kind = parts.next().map(str::trim);
+    let style = parts.next().map(str::trim).unwrap_or_default();
+
+    let Some(value) = args.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str()) else {
+        return format!("{{{}}}", body);
+    };
+
+    let number = value.trim().parse::<f64>().ok();
+    let style_chars = style.chars().collect::<Vec<char>>();
+
+    match (kind, number) {
+        (Some("number"), Some(number)) => match style {
+            "integer" => format_number(number.round(), locale),
+            "percent" => format!("{}%", format_number((number * 100.0).round(), locale)),
+            _ => format_number(number, locale)
+        },
+        (Some("plural"), Some(number)) => {
+            let exact = format!("={}", value.trim());
+            let branches = branches(&style_chars);
+
+            branches
+                .iter()
+                .find(|(selector, _)| *selector == exact)
+                .or_else(|| branches.iter().find(|(selector, _)| selector == plural_category(number, locale)))
+                .or_else(|| branches.iter().find(|(selector, _)| selector == "other"))
+                .map(|(_, message)| render(message, locale, args, Some(number)))
+                .unwrap_or_default()
+        },
+        (Some("selectordinal"), Some(number)) => {
+            let branches = branches(&style_chars);
+
+            branches
+                .iter()
+                .find(|(selector, _)| selector == ordinal_category(number, locale))
+                .or_else(|| branches.iter().find(|(selector, _)| selector == "other"))
+                .map(|(_, message)| render(message, locale, args, Some(number)))
+                .unwrap_or_default()
+        },
+        (Some("select"), _) => {
+            let branches = branches(&style_chars);
+
+            branches
+                .iter()
+                .find(|(selector, _)| selector == value)
+                .or_else(|| branches.iter().find(|(selector, _)| selector == "other"))
+                .map(|(_, message)| render(message, locale, args, None))
+                .unwrap_or_default()
+        },
+        _ => value.to_string()
+    }
+}
+
+/// Splits `one {…} other {…}` into its selectors and submessages.
+fn branches(chars: &[char]) -> Vec<(String, Vec<char>)> {
+    let mut branches = Vec::new();
+    let mut selector = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '{' => {
+                let end = closing(chars, i);
+                let message = chars[i + 1..end.min(chars.len())].to_vec();
+                branches.push((std::mem::take(&mut selector), message));
+                i = end + 1;
+            },
+            c if c.is_whitespace() => i += 1,
+            c => {
+                selector.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    branches
+}
+
+/// Joins items into a readable, localized enumeration such as `❛a❜, ❛b❜ and ❛c❜`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListFormatter {
+    pub open_quote: String,
+    pub close_quote: String,
+    pub separator: String,
+    pub conjunction: String,
+    pub more: String
+}
+
+impl Default for ListFormatter {
+    fn default() -> Self {
+        ListFormatter::for_locale("en")
+    }
+}
+
+impl ListFormatter {
+    /// Creates the formatter for a locale's quotes, separator and conjunction, e.g. `„a“, „b“ und „c“` for `de`.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale, e.g. `en` or `fr-CA`.
+    pub fn for_locale(locale: &str) -> Self {
+        let (open_quote, close_quote, separator, conjunction, more) = match language(locale).as_str() {
+            "de" => ("„", "“", ", ", " und ", "{count} weitere"),
+            "es" => ("«", "»", ", ", " y ", "{count} más"),
+            "fr" => ("«\u{a0}", "\u{a0}»", ", ", " et ", "{count} autres"),
+            "it" => ("«", "»", ", ", " e ", "altri {count}"),
+            "pt" => ("“", "”", ", ", " e ", "mais {count}"),
+            "nl" => ("‘", "’", ", ", " en ", "{count} andere"),
+            "da" | "nb" | "no" => ("«", "»", ", ", " og ", "{count} andre"),
+            "sv" => ("”", "”", ", ", " och ", "{count} till"),
+            "pl" => ("„", "”", ", ", " i ", "{count} innych"),
+            "ru" | "uk" => ("«", "»", ", ", " и ", "ещё {count}"),
+            "id" | "ms" => ("“", "”", ", ", " dan ", "{count} lainnya"),
+            "tl" | "fil" => ("“", "”", ", ", " at ", "{count} pa"),
+            "ja" => ("「", "」", "、", "、", "他{count}件"),
+            "zh" => ("“", "”", "、", "和", "其他{count}项"),
+            "ko" => ("‘", "’", ", ", " 및 ", "외 {count}개"),
+            _ => ("❛", "❜", ", ", " and ", "{count} more")
+        };
+
+        Self {
+            open_quote: open_quote.to_string(),
+            close_quote: close_quote.to_string(),
+            separator: separator.to_string(),
+            conjunction: conjunction.to_string(),
+            more: more.to_string()
+        }
+    }
+
+    /// Sets the quotes wrapped around each item.
+    ///
+    /// # Arguments
+    /// * `open_quote` - The opening quote, e.g. `"`.
+    /// * `close_quote` - The closing quote.
+    pub fn set_quotes<T>(mut self, open_quote: T, close_quote: T) -> Self
+    where T: ToString
+    {
+        self.open_quote = open_quote.to_string();
+        self.close_quote = close_quote.to_string();
+        self
+    }
+
+    /// Sets the separator between items other than the last two.
+    ///
+    /// # Arguments
+    /// * `separator` - The separator, including any spacing, e.g. `", "`.
+    pub fn set_separator<T>(mut self, separator: T) -> Self
+    where T: ToString
+    {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Sets the conjunction between the last two items.
+    ///
+    /// # Arguments
+    /// * `conjunction` - The conjunction, including any spacing, e.g. `" or "`.
+    pub fn set_conjunction<T>(mut self, conjunction: T) -> Self
+    where T: ToString
+    {
+        self.conjunction = conjunction.to_string();
+        self
+    }
+
+    /// Sets the phrase standing in for the items left out by `format_limited`.
+    ///
+    /// # Arguments
+    /// * `more` - The phrase, with `{count}` replaced by the number of items left out, e.g. `"{count} others"`.
+    pub fn set_more<T>(mut self, more: T) -> Self
+    where T: ToString
+    {
+        self.more = more.to_string();
+        self
+    }
+
+    /// Wraps a single item in the quotes.
+    ///
+    /// # Arguments
+    /// * `item` - The item to quote.
+    pub fn quote(&self, item: &str) -> String {
+        format!("{}{}{}", self.open_quote, item, self.close_quote)
+    }
+
+    /// Joins items: one renders on its own, two are joined by the conjunction alone, and longer
+    /// lists separate all but the last pair with the separator.
+    ///
+    /// # Arguments
+    /// * `items` - The items to join.
+    pub fn format<T>(&self, items: &[T]) -> String
+    where T: AsRef<str>
+    {
+        let quoted = items
+            .iter()
+            .map(|item| self.quote(item.as_ref()))
+            .collect::<Vec<String>>();
+
+        match quoted.split_last() {
+            Some((last, others)) if !others.is_empty() => {
+                format!("{}{}{}", others.join(&self.separator), self.conjunction, last)
+            },
+            _ => quoted.join("")
+        }
+    }
+}
+
+impl ListFormatter {
+    /// Joins at most `max` items, ending with the `more` phrase for the rest, e.g. `❛a❜, ❛b❜ and 48 more`.
+    ///
+    /// # Arguments
+    /// * `items` - The items to join.
+    /// * `max` - The maximum number of items to enumerate.
+    pub fn format_limited<T>(&self, items: &[T], max: usize) -> String
+    where T: AsRef<str>
+    {
+        if items.len() <= max {
+            return self.format(items);
+        }
+
+        let more = self.more.replace("{count}", &format_number((items.len() - max) as f64, message::default_locale()));
+        let quoted = items[..max]
+            .iter()
+            .map(|item| self.quote(item.as_ref()))
+            .collect::<Vec<String>>();
+
+        match quoted.is_empty() {
+            true => more,
+            false => format!("{}{}{}", quoted.join(&self.separator), self.conjunction, more)
+        }
+    }
+}
+
+impl Validator {
+    /// Sets the maximum number of options enumerated in messages, summarizing the rest as "and N more".
+    ///
+    /// # Arguments
+    /// * `max_options_in_message` - The maximum number of options listed in the `options` arg.
+    pub fn set_max_options_in_message(mut self, max_options_in_message: usize) -> Self {
+        self.max_options_in_message = Some(max_options_in_message);
+        self
+    }
+
+    /// Formats options for the `options` message arg, honoring `max_options_in_message`.
+    pub(crate) fn format_options<T>(&self, options: &[T]) -> String
+    where T: AsRef<str>
+    {
+        match self.max_options_in_message {
+            Some(max) => self.list_formatter().format_limited(options, max),
+            None => self.list_formatter().format(options)
+        }
+    }
+
+    /// Sets the formatter for option enumerations in messages, e.g. to use `or` or plain quotes.
+    ///
+    /// # Arguments
+    /// * `list_formatter` - The formatter. Defaults to the default locale's formatter.
+    pub fn set_list_formatter(mut self, list_formatter: ListFormatter) -> Self {
+        self.list_formatter = Some(list_formatter);
+        self
+    }
+
+    /// Returns the configured list formatter, or the default locale's.
+    pub(crate) fn list_formatter(&self) -> ListFormatter {
+        match &self.list_formatter {
+            Some(list_formatter) => list_formatter.clone(),
+            None => ListFormatter::for_locale(message::default_locale())
+        }
+    }
+
+    /// Sets a unit appended to `min`/`max` args, e.g. `characters` for "at most 1,000,000 characters".
+    ///
+    /// # Arguments
+    /// * `unit_label` - The unit label.
+    pub fn set_unit_label<T>(mut self, unit_label: T) -> Self
+    where T: ToString
+    {
+        self.unit_label = Some(unit_label.to_string());
+        self
+    }
+
+    /// Configures whether `min`/`max` args are grouped for the default locale, e.g. `1,000,000`.
+    ///
+    /// # Arguments
+    /// * `is_bounds_formatted` - A boolean indicating if bounds are formatted. Off by default, so
+    ///   bounds such as years keep rendering as `2024`.
+    pub fn set_as_formatted_bounds(mut self, is_bounds_formatted: bool) -> Self {
+        self.is_bounds_formatted = is_bounds_formatted;
+        self
+    }
+
+    /// Formats a bound for the `min`/`max` message args, applying digit grouping and the unit label.
+    ///
+    /// Only for bounds measured in the unit label; counts and durations are passed as plain numbers.
+    ///
+    /// # Arguments
+    /// * `value` - The bound.
+    pub(crate) fn bound<T>(&self, value: T) -> String
+    where T: ToString
+    {
+        let value = value.to_string();
+        let value = match self.is_bounds_formatted {
+            true => group_digits(&value, message::default_locale()).unwrap_or(value),
+            false => value
+        };
+
+        match &self.unit_label {
+            Some(unit_label) => format!("{} {}", value, unit_label),
+            None => value
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// A named service area that points are checked against.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Region {
+    /// A latitude/longitude box; a `west` greater than `east` crosses the antimeridian.
+    BoundingBox { south: f64, west: f64, north: f64, east: f64 },
+    /// A polygon of `(lat, lng)` vertices, implicitly closed.
+    Polygon(Vec<(f64, f64)>)
+}
+
+impl Region {
+    /// Checks whether the region contains a point.
+    ///
+    /// # Arguments
+    /// * `lat` - The latitude, in degrees.
+    /// * `lng` - The longitude, in degrees.
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        match self {
+            Region::BoundingBox { south, west, north, east } => {
+                let is_within_lng = match west <= east {
+                    true => (*west..=*east).contains(&lng),
+                    false => lng >= *west || lng <= *east
+                };
+
+                (*south..=*north).contains(&lat) && is_within_lng
+            },
+            Region::Polygon(vertices) => {
+                // Even-odd ray casting, with longitude as x and latitude as y
+                let mut is_inside = false;
+                let mut previous = match vertices.last() {
+                    Some(vertex) => *vertex,
+                    None => return false
+                };
+
+                for &(lat_a, lng_a) in vertices {
+                    let (lat_b, lng_b) = previous;
+
+                    if (lat_a > lat) != (lat_b > lat)
+                        && lng < (lng_b - lng_a) * (lat - lat_a) / (lat_b - lat_a) + lng_a
+                    {
+                        is_inside = !is_inside;
+                    }
+
+                    previous = (lat_a, lng_a);
+                }
+
+                is_inside
+            }
+        }
+    }
+}
+
+impl Validator {
+    /// Adds a named region that points may fall in.
+    ///
+    /// # Arguments
+    /// * `name` - The region's name, reported in errors, e.g. `metro-manila`.
+    /// * `region` - The bounding box or polygon.
+    pub fn set_region<T>(mut self, name: T, region: Region) -> Self
+    where T: ToString
+    {
+        self.regions.push((name.to_string(), region));
+        self
+    }
+
+    /// Validates that the string value is a `lat,lng` point, e.g. `14.5995,120.9842`, inside at least
+    /// one of the regions.
+    ///
+    /// When the point is outside every region, their names are listed in the `regions` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, the point is malformed or out of range,
+    ///   or it lies outside every region.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_point_in_region(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let point = value.split_once(',').and_then(|(lat, lng)| {
+            let lat = lat.trim().parse::<f64>().ok().filter(|lat| (-90.0..=90.0).contains(lat))?;
+            let lng = lng.trim().parse::<f64>().ok().filter(|lng| (-180.0..=180.0).contains(lng))?;
+            Some((lat, lng))
+        });
+
+        let Some((lat, lng)) = point else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        if !self.regions.iter().any(|(_, region)| region.contains(lat, lng)) {
+            let names = self.regions.iter().map(|(name, _)| name.clone()).collect::<Vec<String>>();
+
+            return Null::Value(message::new(format!("{}-outside-region", self.field))
+                .set_args("regions", self.format_options(&names))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
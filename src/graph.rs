@@ -0,0 +1,168 @@
+use nulls::Null;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::{message, Validator};
+
+/// Reads a node ID from a JSON string or number.
+fn node_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None
+    }
+}
+
+/// Reads an edge from a `[from, to]` pair or a `{"from": …, "to": …}` object.
+fn edge(value: &Value) -> Option<(String, String)> {
+    match value {
+        Value::Array(pair) if pair.len() == 2 => Some((node_id(&pair[0])?, node_id(&pair[1])?)),
+        Value::Object(object) => Some((node_id(object.get("from")?)?, node_id(object.get("to")?)?)),
+        _ => None
+    }
+}
+
+/// Finds a cycle with an iterative depth-first search, returning its path with the first node repeated at the end.
+fn find_cycle(nodes: &[String], adjacency: &HashMap<&str, Vec<&str>>) -> Option<Vec<String>> {
+    // 0 = unvisited, 1 = on the current path, 2 = done
+    let mut states = HashMap::<&str, u8>::new();
+
+    for start in nodes {
+        if states.contains_key(start.as_str()) {
+            continue;
+        }
+
+        let mut path = vec![(start.as_str(), 0usize)];
+        states.insert(start, 1);
+
+        while let Some((node, next)) = path.last_mut() {
+            let neighbours = adjacency.get(*node).map(Vec::as_slice).unwrap_or_default();
+
+            let Some(neighbour) = neighbours.get(*next).copied() else {
+                states.insert(node, 2);
+                path.pop();
+                continue;
+            };
+
+            *next += 1;
+
+            match states.get(neighbour) {
+                Some(1) => {
+                    let start = path.iter().position(|(node, _)| *node == neighbour).unwrap_or_default();
+                    let mut cycle = path[start..].iter().map(|(node, _)| node.to_string()).collect::<Vec<String>>();
+                    cycle.push(neighbour.to_string());
+
+                    return Some(cycle);
+                },
+                Some(_) => {},
+                None => {
+                    states.insert(neighbour, 1);
+                    path.push((neighbour, 0));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+impl Validator {
+    /// Sets the maximum number of nodes in a graph.
+    ///
+    /// # Arguments
+    /// * `max_nodes` - The maximum node count.
+    pub fn set_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Validates that the JSON value is a directed acyclic graph, such as task dependencies.
+    ///
+    /// The value is an array of edges, or an object with `nodes` (an array of IDs) and `edges`.
+    /// Edges are `[from, to]` pairs or `{"from": …, "to": …}` objects, and node IDs are strings or
+    /// numbers. When nodes are declared, through `nodes` or the option list, edges must only
+    /// reference declared nodes. A cycle is reported with its path, e.g. `a → b → c → a`, as the
+    /// `cycle` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but null, malformed, has too many nodes, references an unknown node, or contains a cycle.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_dag(&self) -> Null<String> {
+        if self.json_value.is_null() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let invalid = || Null::Value(message::get(format!("{}-invalid", self.field)));
+
+        let (declared, edges) = match &self.json_value {
+            Value::Array(edges) => (self.option_list_string.clone(), edges),
+            Value::Object(object) => {
+                let declared = match object.get("nodes") {
+                    None => self.option_list_string.clone(),
+                    Some(Value::Array(nodes)) => match nodes.iter().map(node_id).collect::<Option<Vec<String>>>() {
+                        Some(nodes) => Some(nodes),
+                        None => return invalid()
+                    },
+                    Some(_) => return invalid()
+                };
+
+                match object.get("edges") {
+                    Some(Value::Array(edges)) => (declared, edges),
+                    _ => return invalid()
+                }
+            },
+            _ => return invalid()
+        };
+
+        let Some(edges) = edges.iter().map(edge).collect::<Option<Vec<(String, String)>>>() else {
+            return invalid();
+        };
+
+        // Nodes in order of first appearance, so reported cycles are deterministic
+        let mut nodes = declared.clone().unwrap_or_default();
+        let mut seen = nodes.iter().cloned().collect::<HashSet<String>>();
+        let declared = declared.map(|declared| declared.into_iter().collect::<HashSet<String>>());
+
+        for (from, to) in &edges {
+            for node in [from, to] {
+                if let Some(declared) = &declared
+                    && !declared.contains(node)
+                {
+                    return Null::Value(message::new(format!("{}-dangling", self.field))
+                        .set_args("node", self.list_formatter().quote(node))
+                        .build());
+                }
+
+                if seen.insert(node.clone()) {
+                    nodes.push(node.clone());
+                }
+            }
+        }
+
+        if let Some(max) = self.max_nodes
+            && nodes.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max-nodes", self.field))
+                .set_args("max", self.bound(max))
+                .set_args("count", nodes.len())
+                .build());
+        }
+
+        let mut adjacency = HashMap::<&str, Vec<&str>>::new();
+
+        for (from, to) in &edges {
+            adjacency.entry(from.as_str()).or_default().push(to.as_str());
+        }
+
+        if let Some(cycle) = find_cycle(&nodes, &adjacency) {
+            return Null::Value(message::new(format!("{}-cycle", self.field))
+                .set_args("cycle", cycle.join(" → "))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
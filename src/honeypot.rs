@@ -0,0 +1,62 @@
+use nulls::Null;
+use std::time::Duration;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Configures whether anti-bot checks fail silently, returning `Null::Null` instead of a message.
+    ///
+    /// Silent failures let callers drop the submission while answering as if it succeeded, so bots
+    /// learn nothing about the check.
+    ///
+    /// # Arguments
+    /// * `is_silent` - A boolean indicating if failures return `Null::Null`.
+    pub fn set_as_silent(mut self, is_silent: bool) -> Self {
+        self.is_silent = is_silent;
+        self
+    }
+
+    /// Sets when the form was issued, as a Unix timestamp in seconds, for the minimum submit time check.
+    ///
+    /// # Arguments
+    /// * `issued_at` - The issue time, usually from a signed hidden field.
+    pub fn set_issued_at(mut self, issued_at: i64) -> Self {
+        self.issued_at = Some(issued_at);
+        self
+    }
+
+    /// Sets the minimum time between issuing and submitting the form; faster submissions are treated as bots.
+    ///
+    /// # Arguments
+    /// * `min_submit_time` - The minimum time a human needs to fill in the form.
+    pub fn set_min_submit_time(mut self, min_submit_time: Duration) -> Self {
+        self.min_submit_time = Some(min_submit_time);
+        self
+    }
+
+    /// Validates that the honeypot field, hidden from humans, was left empty and that the form was not
+    /// submitted faster than the minimum submit time.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the honeypot is filled in (`{field}-bot`) or the form was submitted too fast (`{field}-too-fast`).
+    /// * `Null::Null` - Instead of `Null::Value`, when the validator is silent.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_honeypot(&self) -> Null<String> {
+        let reject = |key: &str| match self.is_silent {
+            true => Null::Null,
+            false => Null::Value(message::get(format!("{}-{}", self.field, key)))
+        };
+
+        if !self.string_value.trim().is_empty() {
+            return reject("bot");
+        }
+
+        if let (Some(issued_at), Some(min)) = (self.issued_at, self.min_submit_time)
+            && self.now().saturating_sub(issued_at) < min.as_secs() as i64
+        {
+            return reject("too-fast");
+        }
+
+        Null::Undefined
+    }
+}
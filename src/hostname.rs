@@ -0,0 +1,61 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+const HOSTNAME_MAX: usize = 253;
+const LABEL_MAX: usize = 63;
+
+/// Checks for a DNS label: letters, digits and inner hyphens, at most 63 characters.
+fn is_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= LABEL_MAX
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+}
+
+/// Checks for a DNS host name: dot-separated labels of letters, digits and inner hyphens, each at most
+/// 63 characters and 253 in total. A single trailing dot is allowed.
+pub(crate) fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+
+    !value.is_empty() && value.len() <= HOSTNAME_MAX && value.split('.').all(is_label)
+}
+
+impl Validator {
+    /// Validates that the string value is an RFC 1123 host name, such as `api.example.com`.
+    ///
+    /// Labels are letters, digits and hyphens, at most 63 characters, and cannot start or end with a
+    /// hyphen; the whole name is at most 253 characters. A single trailing dot is allowed. The first
+    /// offending label is reported in the `label` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too long, or has an invalid label.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_hostname(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = value.strip_suffix('.').unwrap_or(value);
+
+        if value.len() > HOSTNAME_MAX {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(HOSTNAME_MAX))
+                .build());
+        }
+
+        if let Some(label) = value.split('.').find(|label| !is_label(label)) {
+            return Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("label", label)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
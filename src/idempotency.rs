@@ -0,0 +1,95 @@
+use nulls::Null;
+use std::time::Duration;
+
+use crate::chars::{in_class, parse_class};
+use crate::uuid::is_uuid;
+use crate::{message, Validator};
+
+const KEY_MIN: usize = 16;
+const KEY_MAX: usize = 255;
+const KEY_CHARS: &str = "A-Za-z0-9_-";
+
+/// Splits a `<unix seconds>-<token>` key into its timestamp, if it has one.
+fn timestamp_prefix(value: &str) -> Option<i64> {
+    let (prefix, token) = value.split_once('-')?;
+
+    match prefix.len() >= 9 && prefix.bytes().all(|byte| byte.is_ascii_digit()) && !token.is_empty() {
+        true => prefix.parse().ok(),
+        false => None
+    }
+}
+
+impl Validator {
+    /// Sets how old a timestamp-prefixed idempotency key may be.
+    ///
+    /// # Arguments
+    /// * `max_key_age` - The maximum age, measured from the key's Unix timestamp prefix.
+    pub fn set_max_key_age(mut self, max_key_age: Duration) -> Self {
+        self.max_key_age = Some(max_key_age);
+        self
+    }
+
+    /// Validates that the string value is an idempotency key: either a UUID, or an opaque token
+    /// within the length bounds made of the allowed characters.
+    ///
+    /// Lengths default to 16 through 255 characters and allowed characters to `A-Za-z0-9_-`. Keys
+    /// starting with a Unix timestamp in seconds and a `-`, e.g. `1700000000-a1b2c3`, are also checked
+    /// against the maximum key age, and must not be further in the future than the maximum skew.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too short or long, contains other
+    ///   characters, is older than the maximum key age, or is issued too far in the future.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_idempotency_key(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if is_uuid(value) {
+            return Null::Undefined;
+        }
+
+        let min = self.min.unwrap_or(KEY_MIN);
+        let max = self.max.unwrap_or(KEY_MAX);
+
+        if value.len() < min {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if value.len() > max {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        let allowed = parse_class(self.allowed_chars.as_deref().unwrap_or(KEY_CHARS));
+
+        if !value.chars().all(|c| in_class(c, &allowed)) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if let (Some(max_age), Some(issued_at)) = (self.max_key_age, timestamp_prefix(value)) {
+            let (skew, max) = self.skew(issued_at);
+
+            if skew > max {
+                return Null::Value(message::new(format!("{}-skew", self.field))
+                    .set_args("skew", skew.to_string())
+                    .set_args("max", max.to_string())
+                    .build());
+            }
+
+            if skew.saturating_neg() > max_age.as_secs() as i64 {
+                return Null::Value(message::get(format!("{}-expired", self.field)));
+            }
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,120 @@
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+impl Validator {
+    /// Sets the minimum number of ids in an id list.
+    ///
+    /// # Arguments
+    /// * `min_ids` - The minimum id count.
+    pub fn set_min_ids(mut self, min_ids: usize) -> Self {
+        self.min_ids = Some(min_ids);
+        self
+    }
+
+    /// Sets the maximum number of ids in an id list.
+    ///
+    /// # Arguments
+    /// * `max_ids` - The maximum id count.
+    pub fn set_max_ids(mut self, max_ids: usize) -> Self {
+        self.max_ids = Some(max_ids);
+        self
+    }
+
+    /// Sets the inclusive range each id in an id list must fall within.
+    ///
+    /// # Arguments
+    /// * `min` - The smallest id allowed.
+    /// * `max` - The largest id allowed.
+    pub fn set_id_range(mut self, min: i64, max: i64) -> Self {
+        self.id_range = Some((min, max));
+        self
+    }
+
+    /// Configures whether ids in an id list must be unique.
+    ///
+    /// # Arguments
+    /// * `is_unique` - A boolean indicating if repeated ids are rejected.
+    pub fn set_as_unique(mut self, is_unique: bool) -> Self {
+        self.is_unique = is_unique;
+        self
+    }
+
+    /// Validates a comma-separated list of integer ids such as `"1,5,42"`, returning the parsed ids.
+    ///
+    /// Whitespace around each id is ignored. An empty optional value yields an empty list. A malformed
+    /// id is reported with its `value` and its index in the list as `position`, counting from 0.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Vec<i64>>)` - The ids, in their original order, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, an id is not an integer or out of range, an
+    ///   id repeats, or the list violates the minimum/maximum count.
+    pub fn validate_id_list(&self) -> Result<Valid<Vec<i64>>, Error> {
+        let (outcome, ids) = self.parse_id_list();
+        self.to_valid(outcome, ids)
+    }
+
+    /// Parses and checks the id list, returning the first failure alongside the ids parsed so far.
+    fn parse_id_list(&self) -> (Null<String>, Vec<i64>) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), Vec::new()),
+                false => (Null::Undefined, Vec::new())
+            };
+        }
+
+        // Counted before parsing so oversized lists are rejected without allocating
+        let count = value.split(',').count();
+
+        if let Some(max) = self.max_ids
+            && count > max
+        {
+            return (Null::Value(message::new(format!("{}-max-ids", self.field))
+                .set_args("max", self.bound(max))
+                .set_args("count", count)
+                .build()), Vec::new());
+        }
+
+        let mut ids = Vec::with_capacity(count);
+
+        for (index, item) in value.split(',').map(str::trim).enumerate() {
+            let Ok(id) = item.parse::<i64>() else {
+                return (Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("value", item)
+                    .set_args("position", index)
+                    .build()), ids);
+            };
+
+            if let Some((min, max)) = self.id_range
+                && (id < min || id > max)
+            {
+                return (Null::Value(message::new(format!("{}-id-range", self.field))
+                    .set_args("value", id)
+                    .set_args("min", self.bound(min))
+                    .set_args("max", self.bound(max))
+                    .build()), ids);
+            }
+
+            if self.is_unique && ids.contains(&id) {
+                return (Null::Value(message::new(format!("{}-duplicate", self.field))
+                    .set_args("value", id)
+                    .build()), ids);
+            }
+
+            ids.push(id);
+        }
+
+        if let Some(min) = self.min_ids
+            && ids.len() < min
+        {
+            return (Null::Value(message::new(format!("{}-min-ids", self.field))
+                .set_args("min", self.bound(min))
+                .set_args("count", ids.len())
+                .build()), ids);
+        }
+
+        (Null::Undefined, ids)
+    }
+}
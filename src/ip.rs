@@ -0,0 +1,122 @@
+use nulls::Null;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Validates that the string value is an IPv4 or IPv6 address.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or the value is not an IP address.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ip(&self) -> Null<String> {
+        self.validate_address::<IpAddr>()
+    }
+
+    /// Validates that the string value is an IPv4 address in dotted-quad form, e.g. `192.0.2.1`.
+    ///
+    /// Octets with leading zeros, such as `010`, are rejected since clients disagree on whether they are octal.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or the value is not an IPv4 address.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ipv4(&self) -> Null<String> {
+        self.validate_address::<Ipv4Addr>()
+    }
+
+    /// Validates that the string value is an IPv6 address, e.g. `2001:db8::1`, without brackets or a zone.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or the value is not an IPv6 address.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ipv6(&self) -> Null<String> {
+        self.validate_address::<Ipv6Addr>()
+    }
+
+    /// Validates that the string value is a CIDR block such as `10.0.0.0/8` or `2001:db8::/32`.
+    ///
+    /// The minimum/maximum constraints bound the prefix length. Addresses with bits set past the
+    /// prefix, such as `10.0.0.1/8`, are rejected with the intended network in the `network` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a CIDR block, has host bits set, or
+    ///   has a prefix length outside the bounds.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_cidr(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let block = value.split_once('/').and_then(|(address, prefix)| {
+            let address = address.parse::<IpAddr>().ok()?;
+            let bits = match address {
+                IpAddr::V4(_) => 32,
+                IpAddr::V6(_) => 128
+            };
+
+            match !prefix.is_empty() && prefix.len() <= 3 && prefix.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => prefix.parse::<u32>().ok().filter(|prefix| *prefix <= bits).map(|prefix| (address, prefix)),
+                false => None
+            }
+        });
+
+        let Some((address, prefix)) = block else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let network = match address {
+            IpAddr::V4(ip) => IpAddr::V4(Ipv4Addr::from(u32::from(ip) & u32::MAX.checked_shl(32 - prefix).unwrap_or(0))),
+            IpAddr::V6(ip) => IpAddr::V6(Ipv6Addr::from(u128::from(ip) & u128::MAX.checked_shl(128 - prefix).unwrap_or(0)))
+        };
+
+        if network != address {
+            return Null::Value(message::new(format!("{}-host-bits", self.field))
+                .set_args("network", format!("{}/{}", network, prefix))
+                .build());
+        }
+
+        if let Some(min) = self.min
+            && (prefix as usize) < min
+        {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if let Some(max) = self.max
+            && prefix as usize > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the trimmed string value parses as the given address type.
+    fn validate_address<T>(&self) -> Null<String>
+    where T: FromStr
+    {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if value.parse::<T>().is_err() {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,133 @@
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+/// Checks that a language tag is well-formed per BCP 47: a language, then optional script, region,
+/// variants, extensions and private use subtags, e.g. `zh-Hant-TW` or `en-US-x-twain`.
+fn is_language_tag(tag: &str) -> bool {
+    let subtags = tag.split('-').collect::<Vec<&str>>();
+
+    if subtags.iter().any(|subtag| subtag.is_empty() || subtag.len() > 8 || !subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())) {
+        return false;
+    }
+
+    let is_alpha = |subtag: &str| subtag.bytes().all(|byte| byte.is_ascii_alphabetic());
+    let is_digit = |subtag: &str| subtag.bytes().all(|byte| byte.is_ascii_digit());
+
+    // Private use tags such as `x-klingon` stand on their own
+    if subtags[0].eq_ignore_ascii_case("x") {
+        return subtags.len() > 1;
+    }
+
+    if !is_alpha(subtags[0]) || !matches!(subtags[0].len(), 2 | 3 | 5..=8) {
+        return false;
+    }
+
+    let mut rest = &subtags[1..];
+
+    // Extended language subtags, e.g. `zh-yue`
+    while rest.first().is_some_and(|subtag| subtag.len() == 3 && is_alpha(subtag)) {
+        rest = &rest[1..];
+    }
+
+    if rest.first().is_some_and(|subtag| subtag.len() == 4 && is_alpha(subtag)) {
+        rest = &rest[1..];
+    }
+
+    if rest.first().is_some_and(|subtag| (subtag.len() == 2 && is_alpha(subtag)) || (subtag.len() == 3 && is_digit(subtag))) {
+        rest = &rest[1..];
+    }
+
+    while rest.first().is_some_and(|subtag| subtag.len() >= 5 || (subtag.len() == 4 && subtag.as_bytes()[0].is_ascii_digit())) {
+        rest = &rest[1..];
+    }
+
+    // Extensions are a singleton and 2 to 8 character subtags; private use runs to the end
+    while let Some(singleton) = rest.first().filter(|subtag| subtag.len() == 1) {
+        let count = match singleton.eq_ignore_ascii_case("x") {
+            true => rest.len() - 1,
+            false => rest[1..].iter().take_while(|subtag| subtag.len() >= 2).count()
+        };
+
+        if count == 0 {
+            return false;
+        }
+
+        rest = &rest[1 + count..];
+    }
+
+    rest.is_empty()
+}
+
+/// Parses a quality value: `0` to `1` with at most three decimals.
+fn parse_quality(value: &str) -> Option<f64> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    match matches!(whole, "0" | "1") && fraction.len() <= 3 && fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => value.parse::<f64>().ok().filter(|quality| *quality <= 1.0),
+        false => None
+    }
+}
+
+impl Validator {
+    /// Validates that the string value is an `Accept-Language` header, such as `da, en-GB;q=0.8, en;q=0.7`,
+    /// returning the language tags in order of preference.
+    ///
+    /// Each tag must be a well-formed BCP 47 tag or `*`, with an optional `q` weight from 0 to 1. Tags
+    /// are ordered by weight, keeping header order among equal weights, and tags weighted `0` are left out.
+    /// An invalid entry is reported in the `value` arg.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Vec<String>>)` - The preferred tags, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, or an entry is not a weighted language tag.
+    pub fn validate_accept_language(&self) -> Result<Valid<Vec<String>>, Error> {
+        let (outcome, languages) = self.parse_accept_language();
+        self.to_valid(outcome, languages)
+    }
+
+    /// Parses the header into tags ordered by preference.
+    fn parse_accept_language(&self) -> (Null<String>, Vec<String>) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), Vec::new()),
+                false => (Null::Undefined, Vec::new())
+            };
+        }
+
+        let mut languages = Vec::new();
+
+        for entry in value.split(',').map(str::trim) {
+            let mut params = entry.split(';').map(str::trim);
+            let tag = params.next().unwrap_or_default();
+
+            let quality = match params.next().map(|param| param.split_once('=')) {
+                None => Some(1.0),
+                Some(Some((key, quality))) if key.trim().eq_ignore_ascii_case("q") => parse_quality(quality.trim()),
+                Some(_) => None
+            };
+
+            match quality {
+                Some(quality) if params.next().is_none() && (tag == "*" || is_language_tag(tag)) => {
+                    languages.push((tag.to_string(), quality));
+                },
+                _ => {
+                    return (Null::Value(message::new(format!("{}-invalid", self.field))
+                        .set_args("value", entry)
+                        .build()), Vec::new());
+                }
+            }
+        }
+
+        languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let languages = languages
+            .into_iter()
+            .filter(|(_, quality)| *quality > 0.0)
+            .map(|(tag, _)| tag)
+            .collect();
+
+        (Null::Undefined, languages)
+    }
+}
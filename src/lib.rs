@@ -1,8 +1,123 @@
 use chrono::NaiveDate;
 use nulls::Null;
+#[cfg(feature = "regex")]
 use regex::Regex;
+#[cfg(feature = "sizes")]
 use sizes::Size;
-use serde_json::{Map, Value};
+use serde_json::{Map, Number, Value};
+use std::sync::Arc;
+use std::time::Duration;
+
+use license::LicenseChecksum;
+use skew::Clock;
+
+pub mod presets;
+
+mod boolean;
+mod cache;
+#[cfg(feature = "async")]
+mod captcha;
+mod card;
+mod chars;
+mod checksum;
+mod color;
+mod confusables;
+mod context;
+mod crypto;
+mod currency;
+#[cfg(feature = "hmac")]
+mod cursor;
+mod deadline;
+mod definition;
+#[cfg(feature = "psl")]
+mod domain;
+mod distance;
+mod encoding;
+mod etag;
+mod event;
+mod fingerprint;
+mod flags;
+mod form;
+mod format;
+mod geofence;
+mod graph;
+mod honeypot;
+mod hostname;
+mod idempotency;
+mod ip;
+mod ids;
+mod language;
+mod license;
+mod logistics;
+mod measurement;
+mod message;
+mod movement;
+mod numbers;
+mod numerals;
+mod occurrences;
+mod patterns;
+mod phone;
+mod port;
+mod promo;
+mod provider;
+mod range;
+mod registry;
+mod roles;
+mod scopes;
+mod securities;
+mod security;
+mod semver;
+mod skew;
+mod slug;
+#[cfg(feature = "hmac")]
+mod steps;
+mod stock;
+mod structured;
+mod survey;
+mod telemetry;
+mod temporal;
+mod template;
+mod text;
+mod tracking;
+mod transition;
+mod travel;
+mod tree;
+mod typed;
+mod types;
+mod ulid;
+mod url;
+mod username;
+mod uuid;
+
+pub use cache::ValidationCache;
+#[cfg(feature = "async")]
+pub use captcha::{CaptchaVerdict, CaptchaVerifier};
+#[cfg(feature = "confusables")]
+pub use confusables::confusable_skeleton;
+pub use context::Context;
+#[cfg(feature = "email")]
+pub use form::email;
+#[cfg(feature = "regex")]
+pub use form::name;
+pub use form::{float, form, int, one_of, one_of_static, string, Report, Rule, RuleChange, RuleKind, RuleOptions, Shadow, ValidatorSet};
+pub use format::{format_message, format_number, ListFormatter};
+pub use geofence::Region;
+pub use message::{set_default_locale, set_translator, Message, Translator};
+pub use occurrences::DuplicatePolicy;
+pub use phone::split_phone_extension;
+pub use provider::{refresh, FileProvider, OptionLists, Refresher, RuleProvider};
+pub use range::ByteRange;
+pub use registry::RuleRegistry;
+pub use security::HmacAlgorithm;
+pub use survey::{survey, Question, QuestionKind, Survey};
+pub use telemetry::{Sensor, SensorKind};
+pub use template::TemplateSyntax;
+pub use text::TextAnalyzer;
+pub use tracking::Carrier;
+pub use typed::{Error, Valid};
+#[cfg(feature = "email")]
+pub use types::Email;
+pub use types::{PhoneNumber, Slug, Username};
 
 const MIN: usize = 8;
 const MAX: usize = 64;
@@ -22,16 +137,129 @@ pub struct Validator {
     pub len: Option<usize>,
     pub naive_date: Option<NaiveDate>,
     pub option_list_string: Option<Vec<String>>,
+    pub option_dependencies: Vec<(String, Vec<String>)>,
     pub is_case_sensitive: bool,
     pub is_null: bool,
     pub is_required: bool,
+    pub is_suggest_closest: bool,
     pub i32_value: Option<i32>,
     pub i64_value: Option<i64>,
     pub f32_value: Option<f32>,
     pub f64_value: Option<f64>,
     pub string_value: String,
     pub parent_string: String,
-    pub list_sizes_value: Vec<Size>
+    pub parent_value: String,
+    #[cfg(feature = "sizes")]
+    pub list_sizes_value: Vec<Size>,
+    pub map_value: Map<String, Value>,
+    pub max_entries: Option<usize>,
+    pub max_key_len: Option<usize>,
+    pub max_value_len: Option<usize>,
+    pub key_pattern: Option<String>,
+    pub forbidden_keys: Vec<String>,
+    pub json_value: Value,
+    pub max_depth: Option<usize>,
+    pub max_total_nodes: Option<usize>,
+    pub max_string_len: Option<usize>,
+    pub is_dtd_allowed: bool,
+    pub regex_size_limit: Option<usize>,
+    pub is_relative_only: bool,
+    pub template_syntax: TemplateSyntax,
+    pub placeholders: Option<Vec<String>>,
+    pub max_loop_depth: Option<usize>,
+    pub default_value: Option<Value>,
+    pub max_errors: Option<usize>,
+    pub expected_secret: String,
+    pub hmac_key: Vec<u8>,
+    pub hmac_algorithm: HmacAlgorithm,
+    pub signed_payload: Vec<u8>,
+    pub bytes_value: Vec<u8>,
+    pub is_bom_allowed: bool,
+    pub is_surrogates_allowed: bool,
+    pub similar_to: Option<(String, usize)>,
+    pub not_similar_to: Vec<(String, usize)>,
+    pub protected_names: Vec<String>,
+    pub timeout: Option<Duration>,
+    pub unit_label: Option<String>,
+    pub is_bounds_formatted: bool,
+    pub list_formatter: Option<ListFormatter>,
+    pub max_options_in_message: Option<usize>,
+    pub allowed_chars: Option<String>,
+    pub forbidden_chars: Option<String>,
+    pub max_lines: Option<usize>,
+    pub max_line_length: Option<usize>,
+    pub min_words: Option<usize>,
+    pub max_words: Option<usize>,
+    pub text_analyzer: Option<Arc<dyn TextAnalyzer>>,
+    pub allowed_languages: Vec<String>,
+    pub max_gibberish_score: Option<f64>,
+    pub min_ids: Option<usize>,
+    pub max_ids: Option<usize>,
+    pub id_range: Option<(i64, i64)>,
+    pub is_unique: bool,
+    pub allowed_scopes: Vec<String>,
+    pub scope_implications: Vec<(String, Vec<String>)>,
+    pub context: Context,
+    pub contrast_with: Option<(String, String)>,
+    pub min_contrast: Option<f64>,
+    pub max_decimals: Option<usize>,
+    pub ticker_suffixes: Vec<String>,
+    pub is_suffix_required: bool,
+    pub is_testnet: bool,
+    pub is_checksum_required: bool,
+    pub carriers: Vec<Carrier>,
+    pub license_pattern: Option<String>,
+    pub license_checksum: Option<LicenseChecksum>,
+    pub required_prefix: Option<String>,
+    pub blocked_words: Option<Vec<String>>,
+    pub children_key: Option<String>,
+    pub max_children: Option<usize>,
+    pub node_rules: Option<ValidatorSet>,
+    pub max_nodes: Option<usize>,
+    pub transitions: Vec<(String, String)>,
+    pub must_be_after: Option<String>,
+    pub must_be_before: Option<String>,
+    pub available: Option<i64>,
+    pub available_key: Option<String>,
+    pub min_captcha_score: Option<f64>,
+    pub is_silent: bool,
+    pub issued_at: Option<i64>,
+    pub min_submit_time: Option<Duration>,
+    pub max_key_age: Option<Duration>,
+    pub clock: Option<Clock>,
+    pub max_skew: Option<Duration>,
+    pub allowed_schemes: Vec<String>,
+    pub number_locale: Option<String>,
+    pub is_private_host_rejected: bool,
+    pub truthy: Option<Vec<String>>,
+    pub falsey: Option<Vec<String>>,
+    pub uuid_version: Option<u8>,
+    pub occurrences: Vec<String>,
+    pub min_occurrences: Option<usize>,
+    pub max_occurrences: Option<usize>,
+    pub duplicate_policy: Option<DuplicatePolicy>,
+    pub default_region: Option<String>,
+    pub is_etag_list: bool,
+    pub max_span: Option<u64>,
+    pub max_ranges: Option<usize>,
+    pub cursor_ttl: Option<Duration>,
+    pub regions: Vec<(String, Region)>,
+    pub is_well_known_excluded: bool,
+    pub max_speed: Option<f64>,
+    pub previous_fix_key: Option<String>,
+    pub is_registrable_required: bool,
+    pub allowed_units: Vec<String>,
+    pub canonical_unit: Option<String>,
+    pub sensors: &'static [Sensor],
+    pub reserved_list: Vec<String>,
+    pub is_alpha_allowed: bool,
+    pub retention: Option<Duration>,
+    pub previous_timestamp_key: Option<String>,
+    pub reserved_prefixes: Vec<String>,
+    pub is_prerelease_forbidden: bool,
+    pub is_build_metadata_forbidden: bool,
+    pub allowed_brands: Vec<String>,
+    pub step_ttl: Option<Duration>
 }
 
 
@@ -90,6 +318,15 @@ impl Validator {
         self
     }
 
+    /// Configures whether option-list errors should suggest the closest allowed option.
+    ///
+    /// # Arguments
+    /// * `is_suggest_closest` - A boolean indicating if a `suggestion` arg should be included on mismatch.
+    pub fn set_suggest_closest(mut self, is_suggest_closest: bool) -> Self {
+        self.is_suggest_closest = is_suggest_closest;
+        self
+    }
+
     /// Sets the field's value as a nullable `i32`.
     ///
     /// # Arguments
@@ -144,6 +381,15 @@ impl Validator {
         self
     }
 
+    /// Sets the maximum number of errors list validators collect before stopping.
+    ///
+    /// # Arguments
+    /// * `max_errors` - The maximum number of errors to report.
+    pub fn set_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
     /// Sets the f64 minimum value constraint for the field.
     ///
     /// # Arguments
@@ -157,6 +403,7 @@ impl Validator {
     ///
     /// # Arguments
     /// * `list_sizes` - A nullable vector of `Size` values. Defaults to an empty vector if `Null::Undefined` or `Null::Null`.
+    #[cfg(feature = "sizes")]
     pub fn set_list_sizes_value(&mut self, list_sizes: &Null<Vec<Size>>) -> &mut Self {
         self.list_sizes_value = list_sizes.clone().take().unwrap_or_default();
         self
@@ -234,6 +481,32 @@ impl Validator {
         self
     }
 
+    /// Requires the string value to be within an edit distance of a reference value.
+    ///
+    /// # Arguments
+    /// * `reference` - The reference value.
+    /// * `max_distance` - The maximum Levenshtein distance allowed.
+    pub fn set_similar_to<T>(mut self, reference: T, max_distance: usize) -> Self
+    where T: ToString
+    {
+        self.similar_to = Some((reference.to_string(), max_distance));
+        self
+    }
+
+    /// Requires the string value to differ from a reference value by at least a number of edits.
+    ///
+    /// Can be called multiple times, e.g. once per protected admin name.
+    ///
+    /// # Arguments
+    /// * `reference` - The reference value, e.g. the previous password.
+    /// * `min_distance` - The minimum Levenshtein distance required.
+    pub fn set_not_similar_to<T>(mut self, reference: T, min_distance: usize) -> Self
+    where T: ToString
+    {
+        self.not_similar_to.push((reference.to_string(), min_distance));
+        self
+    }
+
     /// Sets the string value for the validator, defaulting to an empty string if null or undefined.
     ///
     /// # Arguments
@@ -252,6 +525,34 @@ impl Validator {
         self
     }
 
+    /// Restricts an option so it is only allowed when the parent field holds one of the given values.
+    ///
+    /// Can be called once per dependent option; the parent field is named through `set_parent_string`
+    /// and its current value is provided through `set_parent_value`.
+    ///
+    /// # Arguments
+    /// * `option` - The option that depends on the parent field.
+    /// * `parents` - A slice of parent values under which the option is allowed.
+    pub fn set_option_dependencies<T, U>(mut self, option: T, parents: &[U]) -> Self
+    where T: ToString, U: ToString
+    {
+        self.option_dependencies.push((
+            option.to_string(),
+            parents.iter().map(|value| value.to_string()).collect::<Vec<String>>()
+        ));
+
+        self
+    }
+
+    /// Sets the parent field's current value, used to resolve option dependencies.
+    ///
+    /// # Arguments
+    /// * `string` - A nullable `String` value.
+    pub fn set_parent_value(mut self, string: &Null<String>) -> Self {
+        self.parent_value = string.clone().take().unwrap_or_default();
+        self
+    }
+
     /// Sets the string value for the validator, defaulting to an empty string if null or undefined.
     ///
     /// # Arguments
@@ -263,20 +564,55 @@ impl Validator {
         self
     }
 
+    /// Sets the default value used when the field is absent.
+    ///
+    /// The default does not affect validation; it is surfaced through `coerced_value`.
+    ///
+    /// # Arguments
+    /// * `value` - The default value, convertible to a JSON value.
+    pub fn set_default<T>(mut self, value: T) -> Self
+    where T: Into<Value>
+    {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    /// Returns the field's value as JSON, falling back to the default value when the field is absent.
+    ///
+    /// The first value set is used, checking the string, integer, float, date, JSON and map values in order.
+    ///
+    /// # Returns
+    /// * `Value` - The field's value, the default value, or `Value::Null` if neither is available.
+    pub fn coerced_value(&self) -> Value {
+        let value = match () {
+            _ if !self.string_value.is_empty() => Some(Value::from(self.string_value.clone())),
+            _ => self.i64_value.map(Value::from)
+                .or_else(|| self.i32_value.map(Value::from))
+                .or_else(|| self.f64_value.and_then(Number::from_f64).map(Value::Number))
+                .or_else(|| self.f32_value.and_then(|value| Number::from_f64(value as f64)).map(Value::Number))
+                .or_else(|| self.naive_date.map(|date| Value::from(date.to_string())))
+                .or_else(|| (!self.json_value.is_null()).then(|| self.json_value.clone()))
+                .or_else(|| (!self.map_value.is_empty()).then(|| Value::Object(self.map_value.clone())))
+        };
+
+        value.or_else(|| self.default_value.clone()).unwrap_or_default()
+    }
+
     /// Validates that the string value is a valid Base64-encoded string of the specified length.
     ///
     /// # Returns
     /// * `Null::Value` - If the field is required but empty, or if the Base64-decoded length does not match the specified length.
     /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "base64")]
     pub fn validate_b64_bytes(&self) -> Null<String> {
         if self.is_required && self.string_value.is_empty() {
-            return nulls::new(i18n::get(format!("{}-invalid", self.field)));
+            return nulls::new(message::get(format!("{}-invalid", self.field)));
         }
 
         if let Some(len) = self.len {
             if let Ok(signing) = base64_url::decode(&self.string_value) {
                 if len != signing.len() {
-                    return nulls::new(i18n::new(format!("{}-len", self.field))
+                    return nulls::new(message::new(format!("{}-len", self.field))
                         .set_args("len", len)
                         .build());
                 }
@@ -291,13 +627,20 @@ impl Validator {
     /// # Returns
     /// * `Null::Value` - If the field is empty or the email format is invalid.
     /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "email")]
     pub fn validate_email(&self) -> Null<String> {
+        message::render(self.check_email())
+    }
+
+    /// Runs `validate_email`, returning the unrendered message behind its error.
+    #[cfg(feature = "email")]
+    pub(crate) fn check_email(&self) -> Null<Message> {
         if self.string_value.is_empty() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::key(format!("{}-empty", self.field)));
         }
 
         if !mailchecker::is_valid(&self.string_value) {
-            return Null::Value(i18n::get(format!("{}-invalid", self.field)));
+            return Null::Value(message::key(format!("{}-invalid", self.field)));
         }
 
         Null::Undefined
@@ -310,30 +653,30 @@ impl Validator {
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_i32(&self) -> Null<String> {
         if self.is_required && self.i32_value.is_none() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::get(format!("{}-empty", self.field)));
         }
 
         if let (Some(min), Some(max), Some(value)) = (self.min, self.max, self.i32_value) {
             if self.is_required && value < min as i32 && value > max as i32 {
-                return Null::Value(i18n::new(format!("{}-min-max", self.field))
-                    .set_args("min", min.to_string())
-                    .set_args("max", max.to_string())
+                return Null::Value(message::new(format!("{}-min-max", self.field))
+                    .set_args("min", self.bound(min))
+                    .set_args("max", self.bound(max))
                     .build());
             }
         }
 
         if let (Some(min), Some(value)) = (self.min, self.i32_value) {
             if self.is_required && value < min as i32 {
-                return Null::Value(i18n::new(format!("{}-min", self.field))
-                    .set_args("min", min.to_string())
+                return Null::Value(message::new(format!("{}-min", self.field))
+                    .set_args("min", self.bound(min))
                     .build());
             }
         }
 
         if let (Some(max), Some(value)) = (self.max, self.i32_value) {
             if self.is_required && value > max as i32 {
-                return Null::Value(i18n::new(format!("{}-max", self.field))
-                    .set_args("max", max.to_string())
+                return Null::Value(message::new(format!("{}-max", self.field))
+                    .set_args("max", self.bound(max))
                     .build());
             }
         }
@@ -347,32 +690,37 @@ impl Validator {
     /// * `Null::Value` - If the field is required but empty, or if it violates the minimum/maximum constraints.
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_i64(&self) -> Null<String> {
+        message::render(self.check_i64())
+    }
+
+    /// Runs `validate_i64`, returning the unrendered message behind its error.
+    pub(crate) fn check_i64(&self) -> Null<Message> {
         if self.is_required && self.i64_value.is_none() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::key(format!("{}-empty", self.field)));
         }
 
         if let (Some(min), Some(max), Some(value)) = (self.min, self.max, self.i64_value) {
             if self.is_required && value < min as i64 && value > max as i64 {
-                return Null::Value(i18n::new(format!("{}-min-max", self.field))
-                    .set_args("min", min.to_string())
-                    .set_args("max", max.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-min-max", self.field))
+                    .set_args("min", self.bound(min))
+                    .set_args("max", self.bound(max))
+                    .into_message());
             }
         }
 
         if let (Some(min), Some(value)) = (self.min, self.i64_value) {
             if self.is_required && value < min as i64 {
-                return Null::Value(i18n::new(format!("{}-min", self.field))
-                    .set_args("min", min.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-min", self.field))
+                    .set_args("min", self.bound(min))
+                    .into_message());
             }
         }
 
         if let (Some(max), Some(value)) = (self.max, self.i64_value) {
             if self.is_required && value > max as i64 {
-                return Null::Value(i18n::new(format!("{}-max", self.field))
-                    .set_args("max", max.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-max", self.field))
+                    .set_args("max", self.bound(max))
+                    .into_message());
             }
         }
 
@@ -386,30 +734,30 @@ impl Validator {
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_f32(&self) -> Null<String> {
         if self.is_required && self.f32_value.is_none() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::get(format!("{}-empty", self.field)));
         }
 
         if let (Some(min), Some(max), Some(value)) = (self.fmin, self.fmax, self.f32_value) {
             if self.is_required && value < min as f32 && value > max as f32 {
-                return Null::Value(i18n::new(format!("{}-min-max", self.field))
-                    .set_args("min", min.to_string())
-                    .set_args("max", max.to_string())
+                return Null::Value(message::new(format!("{}-min-max", self.field))
+                    .set_args("min", self.bound(min))
+                    .set_args("max", self.bound(max))
                     .build());
             }
         }
 
         if let (Some(min), Some(value)) = (self.fmin, self.f32_value) {
             if self.is_required && value < min as f32 {
-                return Null::Value(i18n::new(format!("{}-min", self.field))
-                    .set_args("min", min.to_string())
+                return Null::Value(message::new(format!("{}-min", self.field))
+                    .set_args("min", self.bound(min))
                     .build());
             }
         }
 
         if let (Some(max), Some(value)) = (self.fmax, self.f32_value) {
             if self.is_required && value > max as f32 {
-                return Null::Value(i18n::new(format!("{}-max", self.field))
-                    .set_args("max", max.to_string())
+                return Null::Value(message::new(format!("{}-max", self.field))
+                    .set_args("max", self.bound(max))
                     .build());
             }
         }
@@ -423,32 +771,37 @@ impl Validator {
     /// * `Null::Value` - If the field is required but empty, or if it violates the minimum/maximum constraints.
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_f64(&self) -> Null<String> {
+        message::render(self.check_f64())
+    }
+
+    /// Runs `validate_f64`, returning the unrendered message behind its error.
+    pub(crate) fn check_f64(&self) -> Null<Message> {
         if self.is_required && self.f64_value.is_none() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::key(format!("{}-empty", self.field)));
         }
 
         if let (Some(min), Some(max), Some(value)) = (self.fmin, self.fmax, self.f64_value) {
             if self.is_required && value < min && value > max {
-                return Null::Value(i18n::new(format!("{}-min-max", self.field))
-                    .set_args("min", min.to_string())
-                    .set_args("max", max.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-min-max", self.field))
+                    .set_args("min", self.bound(min))
+                    .set_args("max", self.bound(max))
+                    .into_message());
             }
         }
 
         if let (Some(min), Some(value)) = (self.fmin, self.f64_value) {
             if self.is_required && value < min {
-                return Null::Value(i18n::new(format!("{}-min", self.field))
-                    .set_args("min", min.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-min", self.field))
+                    .set_args("min", self.bound(min))
+                    .into_message());
             }
         }
 
         if let (Some(max), Some(value)) = (self.fmax, self.f64_value) {
             if self.is_required && value > max {
-                return Null::Value(i18n::new(format!("{}-max", self.field))
-                    .set_args("max", max.to_string())
-                    .build());
+                return Null::Value(message::new(format!("{}-max", self.field))
+                    .set_args("max", self.bound(max))
+                    .into_message());
             }
         }
 
@@ -460,15 +813,20 @@ impl Validator {
     /// # Returns
     /// * `Null::Value` - A list of error messages if the field is empty or contains invalid size entries.
     /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "sizes")]
     pub fn validate_list_sizes(&self) -> Null<Vec<String>> {
         let mut errors = Vec::new();
 
         if self.is_required && self.list_sizes_value.is_empty() {
-            errors.push(i18n::get(format!("{}-empty", self.field)).to_string());
+            errors.push(message::get(format!("{}-empty", self.field)).to_string());
         }
 
         if self.is_required && !self.list_sizes_value.is_empty() {
             for size in self.list_sizes_value.clone() {
+                if self.is_over_error_budget(&errors) {
+                    break;
+                }
+
                 let size_scale = ["XXSM", "XSM", "SM", "MD", "LG", "XLG", "XXLG"];
                 let size_type = ["THUMBNAIL", "LANDSCAPE", "PORTRAIT"];
 
@@ -478,7 +836,7 @@ impl Validator {
                 let has_height = size.height > 0;
 
                 if !has_scale || !has_type || !has_width || !has_height {
-                    errors.push(i18n::new(format!("{}-invalid", self.field))
+                    errors.push(message::new(format!("{}-invalid", self.field))
                         .set_args("entry", serde_json::to_string(&size).unwrap_or_default().as_str())
                         .build())
                 }
@@ -489,34 +847,52 @@ impl Validator {
             return Null::Undefined;
         }
 
-        Null::Value(errors)
+        Null::Value(self.cap_errors(errors))
+    }
+
+    /// Checks whether more errors than `max_errors` have been collected, so collection can stop early.
+    fn is_over_error_budget(&self, errors: &[String]) -> bool {
+        self.max_errors.is_some_and(|max| errors.len() > max)
+    }
+
+    /// Caps the errors at `max_errors`, appending a `{field}-truncated` entry when some were dropped.
+    fn cap_errors(&self, mut errors: Vec<String>) -> Vec<String> {
+        if let Some(max) = self.max_errors
+            && errors.len() > max
+        {
+            errors.truncate(max);
+            errors.push(message::new(format!("{}-truncated", self.field))
+                .set_args("max", max.to_string())
+                .build());
+        }
+
+        errors
     }
 
     /// Validates that the string value matches one of the allowed options in the list.
     ///
+    /// When `is_suggest_closest` is set and a close option exists, the `{field}-invalid-suggestion`
+    /// message is returned instead, with the closest option in the `suggestion` arg.
+    ///
     /// # Returns
     /// * `Null::Value` - If the field is required but empty, or if the value is not in the list.
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_list_string(&self) -> Null<String> {
         if self.is_required && self.string_value.is_empty() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::get(format!("{}-empty", self.field)));
         }
 
-        if let Some(list) = self.option_list_string.clone() {
-            match self.is_case_sensitive {
-                true => if self.is_required && !list.contains(&self.string_value) {
-                    return Null::Value(i18n::get(format!("{}-invalid", self.field)));
-                }
-                false => {
-                    let list = list.iter()
-                        .map(|value| value.to_lowercase())
-                        .collect::<Vec<String>>();
-
-                    if self.is_required && !list.contains(&self.string_value.to_lowercase()) {
-                        return Null::Value(i18n::get(format!("{}-invalid", self.field)));
-                    }
-                }
+        if let Some(list) = self.option_list_string.clone()
+            && self.is_required
+            && !self.is_listed(&list)
+        {
+            if let Some(suggestion) = self.closest_option(&list) {
+                return Null::Value(message::new(format!("{}-invalid-suggestion", self.field))
+                    .set_args("suggestion", self.list_formatter().quote(&suggestion))
+                    .build());
             }
+
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
         }
 
         Null::Undefined
@@ -524,77 +900,142 @@ impl Validator {
 
     /// Validates that the string value matches one of the allowed options in the list.
     ///
+    /// When `is_suggest_closest` is set and a close option exists, the `{field}-invalid-suggestion`
+    /// message is returned instead, with the closest option in the `suggestion` arg. The `count` arg
+    /// holds the number of options, so translations can phrase a single option differently.
+    ///
     /// # Returns
-    /// * `Null::Value` - If the field is required but empty, or if the value is not in the list.
+    /// * `Null::Value` - If the field is required but empty, if no options are configured
+    ///   (`{field}-options-unset`), or if the value is not in the list.
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_list_options(&self) -> Null<String> {
+        message::render(self.check_list_options())
+    }
+
+    /// Runs `validate_list_options`, returning the unrendered message behind its error.
+    pub(crate) fn check_list_options(&self) -> Null<Message> {
         if self.is_required && self.string_value.is_empty()  {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::key(format!("{}-empty", self.field)));
         }
 
-        let wrapped_items: Vec<String> = self
-            .option_list_string
-            .clone()
-            .unwrap_or_default()
-            .iter()
-            .map(|item| format!("❛{}❜", item)) // Wrap each item in ❛❜
-            .collect();
-
-        let args = if wrapped_items.len() > 1 {
-            let last = wrapped_items.last().unwrap(); // Get the last element
-            let others = &wrapped_items[..wrapped_items.len() - 1]; // All but the last
-            format!("{} and {}", others.join(", "), last)
-        } else {
-            wrapped_items.join("") // Handles single or empty case
+        if self.string_value.is_empty() && !self.is_required {
+            return Null::Undefined;
+        }
+
+        let Some(list) = self.option_list_string.clone().filter(|list| !list.is_empty()) else {
+            return Null::Value(message::key(format!("{}-options-unset", self.field)));
         };
 
+        let args = self.format_options(&list);
+
         let parent = self.parent_string.clone();
 
-        if let Some(list) = self.option_list_string.clone() {
-            match self.is_case_sensitive {
-                true => if self.is_required && !list.contains(&self.string_value) {
-                    return if parent.is_empty() {
-                        nulls::new(i18n::new(format!("{}-invalid", self.field))
-                            .set_args("options", args.clone())
-                            .build())
-                    } else {
-                        nulls::new(i18n::new(format!("{}-invalid", self.field))
-                            .set_args("options", args.clone())
-                            .set_args("parent", parent.clone())
-                            .build())
-                    }
-                }
-                false => {
-                    let list = list.iter()
-                        .map(|value| value.to_lowercase())
-                        .collect::<Vec<String>>();
-
-                    if self.is_required && !list.contains(&self.string_value.to_lowercase()) {
-                        return if parent.is_empty() {
-                            nulls::new(i18n::new(format!("{}-invalid", self.field))
-                                .set_args("options", args.clone())
-                                .build())
-                        } else {
-                            nulls::new(i18n::new(format!("{}-invalid", self.field))
-                                .set_args("options", args.clone())
-                                .set_args("parent", parent.clone())
-                                .build())
-                        }
-                    }
-                }
+        if self.is_required && !self.is_listed(&list) {
+            let suggestion = self.closest_option(&list);
+            let key = match suggestion {
+                Some(_) => format!("{}-invalid-suggestion", self.field),
+                None => format!("{}-invalid", self.field)
+            };
+
+            let mut message = message::new(key)
+                .set_args("options", args.clone())
+                .set_args("count", list.len().to_string());
+
+            if !parent.is_empty() {
+                message = message.set_args("parent", parent.clone());
+            }
+
+            if let Some(suggestion) = suggestion {
+                message = message.set_args("suggestion", self.list_formatter().quote(&suggestion));
+            }
+
+            return nulls::new(message.into_message());
+        }
+
+        self.check_option_dependencies()
+    }
+
+    /// Validates that the string value's option dependencies are satisfied by the parent value.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the selected option requires the parent field to hold a different value.
+    /// * `Null::Undefined` - If the validation passes successfully or the option has no dependencies.
+    pub fn validate_option_dependencies(&self) -> Null<String> {
+        message::render(self.check_option_dependencies())
+    }
+
+    /// Runs `validate_option_dependencies`, returning the unrendered message behind its error.
+    fn check_option_dependencies(&self) -> Null<Message> {
+        if self.string_value.is_empty() {
+            return Null::Undefined;
+        }
+
+        let matches = |a: &String, b: &String| match self.is_case_sensitive {
+            true => a == b,
+            false => a.to_lowercase() == b.to_lowercase()
+        };
+
+        for (option, parents) in &self.option_dependencies {
+            if matches(option, &self.string_value)
+                && !parents.iter().any(|parent| matches(parent, &self.parent_value))
+            {
+                return nulls::new(message::new(format!("{}-dependency", self.field))
+                    .set_args("option", self.list_formatter().quote(option))
+                    .set_args("parent", self.parent_string.clone())
+                    .set_args("options", self.format_options(parents))
+                    .into_message());
             }
         }
 
         Null::Undefined
     }
 
+    /// Checks whether the string value is contained in the list, honoring case sensitivity.
+    fn is_listed(&self, list: &[String]) -> bool {
+        match self.is_case_sensitive {
+            true => list.contains(&self.string_value),
+            false => {
+                let value = self.string_value.to_lowercase();
+                list.iter().any(|item| item.to_lowercase() == value)
+            }
+        }
+    }
+
+    /// Finds the option closest to the string value by edit distance, if suggestions are enabled.
+    ///
+    /// Options further than half of the longer string's length are not considered close.
+    fn closest_option(&self, list: &[String]) -> Option<String> {
+        if !self.is_suggest_closest || self.string_value.is_empty() {
+            return None;
+        }
+
+        let value = match self.is_case_sensitive {
+            true => self.string_value.clone(),
+            false => self.string_value.to_lowercase()
+        };
+
+        list.iter()
+            .map(|item| {
+                let candidate = match self.is_case_sensitive {
+                    true => item.clone(),
+                    false => item.to_lowercase()
+                };
+
+                let limit = value.chars().count().max(candidate.chars().count()) / 2;
+                (item, distance::levenshtein(&value, &candidate), limit)
+            })
+            .filter(|(_, distance, limit)| distance <= limit)
+            .min_by_key(|(_, distance, _)| *distance)
+            .map(|(item, _, _)| item.clone())
+    }
+
     /// Validates that the naive date value is not empty.
     ///
     /// # Returns
     /// * `Null::String` - If the field is empty
     pub fn validate_naive_date(&self) -> Null<String> {
         if self.is_required && self.naive_date.is_none() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::get(format!("{}-empty", self.field)));
         }
 
         Null::Undefined
@@ -603,19 +1044,30 @@ impl Validator {
     /// Validates that the string value is a valid name format, containing only letters, spaces, and certain special characters.
     ///
     /// # Returns
-    /// * `Null::Value` - If the field is required but empty, or if the value does not match the valid name pattern.
+    /// * `Null::Value` - If the field is required but empty, if the value does not match the valid name pattern, or on timeout.
     /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "regex")]
     pub fn validate_name(&self) -> Null<String> {
-        let value = self.validate_string();
+        message::render(self.check_name())
+    }
+
+    /// Runs `validate_name`, returning the unrendered message behind its error.
+    #[cfg(feature = "regex")]
+    pub(crate) fn check_name(&self) -> Null<Message> {
+        if self.timeout.is_some() {
+            return self.run_with_timeout(Validator::check_name).unwrap_or_else(Null::Value);
+        }
+
+        let value = self.check_string();
         if value.is_some() {
             return value;
         }
 
         match Regex::new(r"^[\p{L} \-・']+$") {
             Ok(re) => if !re.is_match(&self.string_value) {
-                return Null::Value(i18n::get(format!("{}-invalid", self.field)));
+                return Null::Value(message::key(format!("{}-invalid", self.field)));
             },
-            _ => return Null::Value(i18n::get(format!("{}-invalid", self.field)))
+            _ => return Null::Value(message::key(format!("{}-invalid", self.field)))
         }
 
         Null::Undefined
@@ -642,8 +1094,8 @@ impl Validator {
         if length < MIN {
             errors.insert(
                 "minimum".into(),
-                i18n::new(format!("{}-minimum", self.field))
-                    .set_args("min", MIN)
+                message::new(format!("{}-minimum", self.field))
+                    .set_args("min", self.bound(MIN))
                     .build()
                     .into()
             );
@@ -652,8 +1104,8 @@ impl Validator {
         if length > MAX {
             errors.insert(
                 "maximum".into(),
-                i18n::new(format!("{}-maximum", self.field))
-                    .set_args("max", MAX)
+                message::new(format!("{}-maximum", self.field))
+                    .set_args("max", self.bound(MAX))
                     .build()
                     .into()
             );
@@ -665,7 +1117,7 @@ impl Validator {
             .any(|b| b.is_ascii_lowercase()) {
             errors.insert(
                 "lowercase".into(),
-                i18n::get(format!("{}-lowercase", self.field)).into()
+                message::get(format!("{}-lowercase", self.field)).into()
             );
         }
 
@@ -675,7 +1127,7 @@ impl Validator {
             .any(|b| b.is_ascii_uppercase()) {
             errors.insert(
                 "uppercase".into(),
-                i18n::get(format!("{}-uppercase", self.field)).into()
+                message::get(format!("{}-uppercase", self.field)).into()
             );
         }
 
@@ -685,7 +1137,7 @@ impl Validator {
             .all(|x| x.is_ascii_alphabetic()) {
             errors.insert(
                 "number".into(),
-                i18n::get(format!("{}-number", self.field)).into()
+                message::get(format!("{}-number", self.field)).into()
             );
         }
 
@@ -695,7 +1147,7 @@ impl Validator {
             .all(|x| x.is_ascii_alphanumeric()) {
             errors.insert(
                 "symbol".into(),
-                i18n::get(format!("{}-symbol", self.field)).into()
+                message::get(format!("{}-symbol", self.field)).into()
             );
         }
 
@@ -706,15 +1158,80 @@ impl Validator {
         Null::Undefined
     }
 
-    /// Validates that the string value meets length constraints and is not empty.
+    /// Validates that the string value meets length, character and similarity constraints and is not empty.
     ///
     /// # Returns
-    /// * `Null::Value` - If the string is empty or violates the minimum/maximum length constraints.
+    /// * `Null::Value` - If the string is empty, violates the minimum/maximum length constraints, the character policies, or the similarity constraints.
     /// * `Null::Undefined` - If the validation passes successfully.
     pub fn validate_string(&self) -> Null<String> {
+        message::render(self.check_string())
+    }
+
+    /// Runs `validate_string`, returning the unrendered message behind its error.
+    pub(crate) fn check_string(&self) -> Null<Message> {
+        let value = self.check_string_length();
+        if value.is_some() {
+            return value;
+        }
+
+        let value = self.check_chars();
+        if value.is_some() {
+            return value;
+        }
+
+        self.check_similarity()
+    }
+
+    /// Validates that the string value is within `similar_to` and far enough from every `not_similar_to` reference.
+    ///
+    /// Comparisons honor the case sensitivity flag. The references themselves are not included in the
+    /// error args, since they may be secrets such as a previous password.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the value is too different from, or too similar to, a reference.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_similarity(&self) -> Null<String> {
+        message::render(self.check_similarity())
+    }
+
+    /// Runs `validate_similarity`, returning the unrendered message behind its error.
+    fn check_similarity(&self) -> Null<Message> {
+        let normalize = |value: &str| match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_lowercase()
+        };
+
+        let value = normalize(&self.string_value);
+
+        if let Some((reference, max)) = &self.similar_to
+            && distance::levenshtein(&value, &normalize(reference)) > *max
+        {
+            return Null::Value(message::new(format!("{}-not-similar", self.field))
+                .set_args("max", self.bound(max))
+                .into_message());
+        }
+
+        for (reference, min) in &self.not_similar_to {
+            if distance::levenshtein(&value, &normalize(reference)) < *min {
+                return Null::Value(message::new(format!("{}-too-similar", self.field))
+                    .set_args("min", self.bound(min))
+                    .into_message());
+            }
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value meets length constraints and is not empty.
+    fn validate_string_length(&self) -> Null<String> {
+        message::render(self.check_string_length())
+    }
+
+    /// Runs `validate_string_length`, returning the unrendered message behind its error.
+    fn check_string_length(&self) -> Null<Message> {
         // Check if string is empty
         if self.string_value.is_empty() {
-            return Null::Value(i18n::get(format!("{}-empty", self.field)));
+            return Null::Value(message::key(format!("{}-empty", self.field)));
         }
 
         match () {
@@ -725,20 +1242,20 @@ impl Validator {
 
                 match () {
                     _ if len < min && len > max => {
-                        Null::Value(i18n::new(format!("{}-min-max", self.field))
-                            .set_args("min", min.to_string())
-                            .set_args("max", max.to_string())
-                            .build())
+                        Null::Value(message::new(format!("{}-min-max", self.field))
+                            .set_args("min", self.bound(min))
+                            .set_args("max", self.bound(max))
+                            .into_message())
                     },
                     _ if len < min => {
-                        Null::Value(i18n::new(format!("{}-min", self.field))
-                            .set_args("min", min.to_string())
-                            .build())
+                        Null::Value(message::new(format!("{}-min", self.field))
+                            .set_args("min", self.bound(min))
+                            .into_message())
                     },
                     _ if len > max => {
-                        Null::Value(i18n::new(format!("{}-max", self.field))
-                            .set_args("max", max.to_string())
-                            .build())
+                        Null::Value(message::new(format!("{}-max", self.field))
+                            .set_args("max", self.bound(max))
+                            .into_message())
                     },
                     _ => Null::Undefined
                 }
@@ -748,9 +1265,9 @@ impl Validator {
                 let len = self.string_value.len();
 
                 if len < min {
-                    return Null::Value(i18n::new(format!("{}-min", self.field))
-                        .set_args("min", min.to_string())
-                        .build());
+                    return Null::Value(message::new(format!("{}-min", self.field))
+                        .set_args("min", self.bound(min))
+                        .into_message());
                 }
 
                 Null::Undefined
@@ -760,9 +1277,9 @@ impl Validator {
                 let len = self.string_value.len();
 
                 if len > max {
-                    return Null::Value(i18n::new(format!("{}-max", self.field))
-                        .set_args("max", max.to_string())
-                        .build());
+                    return Null::Value(message::new(format!("{}-max", self.field))
+                        .set_args("max", self.bound(max))
+                        .into_message());
                 };
 
                 Null::Undefined
@@ -770,4 +1287,4 @@ impl Validator {
             _ => Null::Undefined
         }
     }
-}
\ No newline at end of file
+}
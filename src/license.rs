@@ -0,0 +1,104 @@
+use nulls::Null;
+use std::sync::Arc;
+
+use crate::{chars, message, Validator};
+
+const LICENSE_PATTERN: &str = "XXXX-XXXX-XXXX-XXXX";
+const LICENSE_CHARS: &str = "A-HJ-NP-Z2-9";
+
+/// Computes the expected checksum group of a license key from its other key characters.
+pub(crate) type LicenseChecksum = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+impl Validator {
+    /// Sets the layout of license keys, where each `X` is a key character and anything else is a
+    /// literal separator.
+    ///
+    /// # Arguments
+    /// * `license_pattern` - The layout. Defaults to `XXXX-XXXX-XXXX-XXXX`.
+    pub fn set_license_pattern<T>(mut self, license_pattern: T) -> Self
+    where T: ToString
+    {
+        self.license_pattern = Some(license_pattern.to_string());
+        self
+    }
+
+    /// Makes the last group of license keys a checksum over the other key characters.
+    ///
+    /// # Arguments
+    /// * `checksum` - A function computing the expected last group from the preceding key characters,
+    ///   without separators.
+    pub fn set_license_checksum<F>(mut self, checksum: F) -> Self
+    where F: Fn(&str) -> String + Send + Sync + 'static
+    {
+        self.license_checksum = Some(Arc::new(checksum));
+        self
+    }
+
+    /// Validates that the string value is a license key matching the license pattern.
+    ///
+    /// Key characters must be in the allowed characters, which default to uppercase letters and digits
+    /// without the easily misread `0`, `1`, `I` and `O`. Lowercase is accepted unless the validator is
+    /// case sensitive. With a license checksum, the last group must match it. A disallowed character is
+    /// reported through `{field}-invalid-char` with the `char` and its 0-based `position` in the key.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, does not match the pattern, contains a
+    ///   disallowed character, or has a wrong checksum group.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_license_key(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_uppercase()
+        };
+
+        let pattern = self.license_pattern.as_deref().unwrap_or(LICENSE_PATTERN);
+        let class = chars::parse_class(self.allowed_chars.as_deref().unwrap_or(LICENSE_CHARS));
+
+        if value.chars().count() != pattern.chars().count() {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        for (index, (c, expected)) in value.chars().zip(pattern.chars()).enumerate() {
+            if expected != 'X' && c != expected {
+                return Null::Value(message::get(format!("{}-invalid", self.field)));
+            }
+
+            if expected == 'X' && !chars::in_class(c, &class) {
+                return Null::Value(message::new(format!("{}-invalid-char", self.field))
+                    .set_args("char", c)
+                    .set_args("position", index)
+                    .build());
+            }
+        }
+
+        if let Some(checksum) = &self.license_checksum {
+            // The checksum group is the key characters after the pattern's last separator
+            let group = pattern.chars().rev().take_while(|c| *c == 'X').count();
+            let key = value
+                .chars()
+                .zip(pattern.chars())
+                .filter(|(_, expected)| *expected == 'X')
+                .map(|(c, _)| c)
+                .collect::<Vec<char>>();
+
+            let (data, actual) = key.split_at(key.len() - group);
+            let data = data.iter().collect::<String>();
+            let actual = actual.iter().collect::<String>();
+
+            if checksum(&data) != actual {
+                return Null::Value(message::get(format!("{}-checksum", self.field)));
+            }
+        }
+
+        Null::Undefined
+    }
+}
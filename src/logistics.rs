@@ -0,0 +1,54 @@
+use nulls::Null;
+
+use crate::{checksum, message, Validator};
+
+impl Validator {
+    /// Validates that the string value is a GTIN-8, GTIN-12 (UPC-A), GTIN-13 (EAN-13) or GTIN-14.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or has a wrong check digit.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_gtin(&self) -> Null<String> {
+        self.validate_gs1(&[8, 12, 13, 14])
+    }
+
+    /// Validates that the string value is an 18-digit SSCC (Serial Shipping Container Code).
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or has a wrong check digit.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_sscc(&self) -> Null<String> {
+        self.validate_gs1(&[18])
+    }
+
+    /// Validates a GS1 identification key of one of the given lengths and its mod 10 check digit.
+    ///
+    /// A wrong check digit is reported with the `expected` digit, so typos can be told apart from
+    /// codes that were truncated or mistyped elsewhere.
+    fn validate_gs1(&self, lengths: &[usize]) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(digits) = checksum::digits(value).filter(|digits| lengths.contains(&digits.len())) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let (check, data) = digits.split_last().unwrap_or((&0, &[]));
+        let expected = checksum::gs1_check_digit(data);
+
+        if expected != *check {
+            return Null::Value(message::new(format!("{}-checksum", self.field))
+                .set_args("expected", expected)
+                .set_args("actual", check)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
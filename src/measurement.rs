@@ -0,0 +1,194 @@
+use nulls::Null;
+
+use crate::{message, presets, Error, Valid, Validator};
+
+/// Known units as `(unit, base unit, factor, offset)`, where a value in the unit converts to its base
+/// unit as `value * factor + offset`. Units sharing a base unit measure the same quantity.
+const UNITS: [(&str, &str, f64, f64); 33] = [
+    ("mcg", "kg", 1e-9, 0.0),
+    ("µg", "kg", 1e-9, 0.0),
+    ("mg", "kg", 1e-6, 0.0),
+    ("g", "kg", 1e-3, 0.0),
+    ("kg", "kg", 1.0, 0.0),
+    ("oz", "kg", 0.028_349_523_125, 0.0),
+    ("lb", "kg", 0.453_592_37, 0.0),
+    ("lbs", "kg", 0.453_592_37, 0.0),
+    ("st", "kg", 6.350_293_18, 0.0),
+    ("mm", "m", 1e-3, 0.0),
+    ("cm", "m", 1e-2, 0.0),
+    ("m", "m", 1.0, 0.0),
+    ("km", "m", 1e3, 0.0),
+    ("in", "m", 0.0254, 0.0),
+    ("ft", "m", 0.3048, 0.0),
+    ("mi", "m", 1_609.344, 0.0),
+    ("°C", "°C", 1.0, 0.0),
+    ("C", "°C", 1.0, 0.0),
+    ("°F", "°C", 5.0 / 9.0, -160.0 / 9.0),
+    ("F", "°C", 5.0 / 9.0, -160.0 / 9.0),
+    ("K", "°C", 1.0, -273.15),
+    ("mL", "L", 1e-3, 0.0),
+    ("dL", "L", 1e-1, 0.0),
+    ("L", "L", 1.0, 0.0),
+    ("Pa", "kPa", 1e-3, 0.0),
+    ("hPa", "kPa", 1e-1, 0.0),
+    ("kPa", "kPa", 1.0, 0.0),
+    ("bar", "kPa", 100.0, 0.0),
+    ("psi", "kPa", 6.894_757_293_168, 0.0),
+    ("mmHg", "kPa", 0.133_322_387_415, 0.0),
+    ("bpm", "bpm", 1.0, 0.0),
+    ("breaths/min", "breaths/min", 1.0, 0.0),
+    ("%", "%", 1.0, 0.0)
+];
+
+/// Looks up a unit, exactly or ignoring ASCII case, returning its entry in the unit table.
+fn find_unit(unit: &str, is_case_sensitive: bool) -> Option<(&'static str, &'static str, f64, f64)> {
+    UNITS.iter()
+        .find(|(name, ..)| *name == unit)
+        .or_else(|| match is_case_sensitive {
+            true => None,
+            false => UNITS.iter().find(|(name, ..)| name.eq_ignore_ascii_case(unit))
+        })
+        .copied()
+}
+
+/// Splits a measurement such as `72.5 kg` or `180cm` into its number and unit.
+fn split_measurement(value: &str) -> Option<(f64, &str)> {
+    let split = value
+        .char_indices()
+        .find(|(index, c)| !(c.is_ascii_digit() || *c == '.' || (*index == 0 && (*c == '-' || *c == '+'))))
+        .map(|(index, _)| index)
+        .unwrap_or(value.len());
+
+    let (number, unit) = value.split_at(split);
+    let unit = unit.trim_start();
+
+    match number.ends_with('.') || unit.is_empty() {
+        true => None,
+        false => number.parse::<f64>().ok().map(|number| (number, unit))
+    }
+}
+
+impl Validator {
+    /// Sets the units a measurement may be written in.
+    ///
+    /// # Arguments
+    /// * `allowed_units` - A slice of unit symbols, e.g. `&["kg", "lb"]`. Defaults to every known unit
+    ///   of the canonical unit's quantity.
+    pub fn set_allowed_units<T>(mut self, allowed_units: &[T]) -> Self
+    where T: ToString
+    {
+        self.allowed_units = allowed_units.iter().map(|unit| unit.to_string()).collect();
+        self
+    }
+
+    /// Sets the unit measurements are converted to before the range checks.
+    ///
+    /// # Arguments
+    /// * `canonical_unit` - A unit symbol such as `kg` or `cm`. Defaults to the first allowed unit.
+    pub fn set_canonical_unit<T>(mut self, canonical_unit: T) -> Self
+    where T: ToString
+    {
+        self.canonical_unit = Some(canonical_unit.to_string());
+        self
+    }
+
+    /// Applies the canonical unit and `fmin`/`fmax` bounds of a named range from
+    /// `presets::measurements()`, such as `body-temperature` (30 to 45 °C) or `heart-rate` (20 to 250 bpm).
+    ///
+    /// Unknown names leave the validator unchanged. Bounds set afterwards override the preset's.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the preset.
+    pub fn set_measurement_preset(mut self, name: &str) -> Self {
+        if let Some((_, unit, min, max)) = presets::measurements().iter().find(|(preset, ..)| *preset == name) {
+            self.canonical_unit = Some(unit.to_string());
+            self.fmin = Some(*min);
+            self.fmax = Some(*max);
+        }
+
+        self
+    }
+
+    /// Parses the string value as a number followed by a unit, such as `72.5 kg` or `180cm`, returning
+    /// it converted to the canonical unit within the `fmin`/`fmax` bounds, which are given in the
+    /// canonical unit.
+    ///
+    /// Units are matched ignoring ASCII case unless the validator is case sensitive. A unit that is not
+    /// allowed, or cannot be converted to the canonical unit, reports the allowed units in the `options` arg.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<f64>)` - The measurement in the canonical unit, or `0.0` for an empty optional value,
+    ///   if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, is not a number and unit, uses a unit that is
+    ///   not allowed, or is out of bounds.
+    pub fn validate_measurement(&self) -> Result<Valid<f64>, Error> {
+        let (outcome, value) = self.parse_measurement();
+        self.to_valid(outcome, value)
+    }
+
+    /// Parses the measurement and converts it to the canonical unit, returning the first failure
+    /// alongside the converted value.
+    fn parse_measurement(&self) -> (Null<String>, f64) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), 0.0),
+                false => (Null::Undefined, 0.0)
+            };
+        }
+
+        let Some((number, unit)) = split_measurement(value) else {
+            return (Null::Value(message::get(format!("{}-invalid", self.field))), 0.0);
+        };
+
+        let canonical = self.canonical_unit.as_deref()
+            .or(self.allowed_units.first().map(String::as_str))
+            .and_then(|canonical| find_unit(canonical, self.is_case_sensitive));
+
+        let is_allowed = |name: &str| self.allowed_units.is_empty() || self.allowed_units.iter().any(|allowed| {
+            find_unit(allowed, self.is_case_sensitive).is_some_and(|(allowed, ..)| allowed == name)
+        });
+
+        let (from, to) = match (find_unit(unit, self.is_case_sensitive), canonical) {
+            (Some(from), Some(to)) if from.1 == to.1 && is_allowed(from.0) => (from, to),
+            (Some(from), None) if is_allowed(from.0) => (from, from),
+            _ => {
+                let options = match self.allowed_units.is_empty() {
+                    true => UNITS.iter()
+                        .filter(|(_, base, ..)| canonical.is_none_or(|canonical| canonical.1 == *base))
+                        .map(|(name, ..)| name.to_string())
+                        .collect::<Vec<String>>(),
+                    false => self.allowed_units.clone()
+                };
+
+                return (Null::Value(message::new(format!("{}-unit", self.field))
+                    .set_args("unit", unit)
+                    .set_args("options", self.format_options(&options))
+                    .build()), 0.0);
+            }
+        };
+
+        let value = ((number * from.2 + from.3) - to.3) / to.2;
+
+        if let Some(min) = self.fmin
+            && value < min
+        {
+            return (Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .set_args("unit", to.0)
+                .build()), value);
+        }
+
+        if let Some(max) = self.fmax
+            && value > max
+        {
+            return (Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .set_args("unit", to.0)
+                .build()), value);
+        }
+
+        (Null::Undefined, value)
+    }
+}
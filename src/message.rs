@@ -0,0 +1,161 @@
+use nulls::Null;
+use std::sync::OnceLock;
+
+use crate::format;
+
+static TRANSLATOR: OnceLock<Box<dyn Translator>> = OnceLock::new();
+static DEFAULT_LOCALE: OnceLock<String> = OnceLock::new();
+
+/// Renders message keys in a specific locale, for `Report::render_errors`.
+pub trait Translator: Send + Sync {
+    /// Renders a message key with its args in a locale. Offsets such as `position` count from 0.
+    ///
+    /// # Returns
+    /// * `Some(String)` - The localized message.
+    /// * `None` - If the locale or key is unknown; the message falls back to the default locale.
+    fn translate(&self, locale: &str, key: &str, args: &[(String, String)]) -> Option<String>;
+}
+
+impl<F> Translator for F
+where F: Fn(&str, &str, &[(String, String)]) -> Option<String> + Send + Sync
+{
+    fn translate(&self, locale: &str, key: &str, args: &[(String, String)]) -> Option<String> {
+        self(locale, key, args)
+    }
+}
+
+/// Installs the process-wide translator used to render errors in several locales.
+///
+/// # Arguments
+/// * `translator` - The translator, e.g. a closure over the application's locale bundles.
+///
+/// # Returns
+/// * `true` - If the translator was installed.
+/// * `false` - If a translator was already installed.
+pub fn set_translator<T>(translator: T) -> bool
+where T: Translator + 'static
+{
+    TRANSLATOR.set(Box::new(translator)).is_ok()
+}
+
+/// Sets the locale used for number formatting and plural rules in default-locale messages. Defaults to `en`.
+///
+/// # Arguments
+/// * `locale` - The locale of the default i18n bundle, e.g. `de`.
+///
+/// # Returns
+/// * `true` - If the locale was set.
+/// * `false` - If a default locale was already set.
+pub fn set_default_locale<T>(locale: T) -> bool
+where T: ToString
+{
+    DEFAULT_LOCALE.set(locale.to_string()).is_ok()
+}
+
+/// Returns the locale set with `set_default_locale`, or `en`.
+pub(crate) fn default_locale() -> &'static str {
+    DEFAULT_LOCALE.get().map(String::as_str).unwrap_or("en")
+}
+
+/// An error message before localization: its i18n key and args.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Message {
+    pub key: String,
+    pub args: Vec<(String, String)>
+}
+
+impl Message {
+    /// Renders the message in the default locale.
+    ///
+    /// Translations using ICU `number`, `plural`, `select` or `selectordinal` arguments are formatted
+    /// with `format_message`; other translations are interpolated by i18n as before.
+    pub fn render(&self) -> String {
+        let template = i18n::get(self.key.clone());
+
+        if self.args.is_empty() {
+            return template;
+        }
+
+        if format::is_icu(&template) {
+            return format::format_message(&template, default_locale(), &self.args);
+        }
+
+        self.args
+            .iter()
+            .fold(i18n::new(self.key.clone()), |builder, (key, value)| builder.set_args(key.as_str(), value.as_str()))
+            .build()
+    }
+
+    /// Renders the message in a locale through the installed `Translator`, falling back to the default locale.
+    ///
+    /// Translators may return ICU MessageFormat patterns, which are formatted for the locale.
+    ///
+    /// # Arguments
+    /// * `locale` - The locale, e.g. `ja`.
+    pub fn render_in(&self, locale: &str) -> String {
+        let Some(translated) = TRANSLATOR
+            .get()
+            .and_then(|translator| translator.translate(locale, &self.key, &self.args))
+        else {
+            return self.render();
+        };
+
+        match format::is_icu(&translated) {
+            true => format::format_message(&translated, locale, &self.args),
+            false => translated
+        }
+    }
+}
+
+/// Builds a message with args, mirroring `i18n::new(...).set_args(...).build()`.
+pub(crate) struct Builder(Message);
+
+impl Builder {
+    /// Adds an arg to the message.
+    pub(crate) fn set_args<K, V>(mut self, key: K, value: V) -> Self
+    where K: ToString, V: ToString
+    {
+        self.0.args.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Renders the message in the default locale.
+    pub(crate) fn build(self) -> String {
+        self.0.render()
+    }
+
+    /// Finishes the message without rendering it, for callers that keep it for other locales.
+    pub(crate) fn into_message(self) -> Message {
+        self.0
+    }
+}
+
+/// Starts building a message with args.
+pub(crate) fn new<T>(key: T) -> Builder
+where T: ToString
+{
+    Builder(Message { key: key.to_string(), args: Vec::new() })
+}
+
+/// Renders a message without args.
+pub(crate) fn get<T>(key: T) -> String
+where T: ToString
+{
+    new(key).build()
+}
+
+/// Returns a message without args, like `get` without rendering it.
+pub(crate) fn key<T>(key: T) -> Message
+where T: ToString
+{
+    new(key).into_message()
+}
+
+/// Renders the message behind a validation outcome in the default locale.
+pub(crate) fn render(outcome: Null<Message>) -> Null<String> {
+    match outcome {
+        Null::Value(message) => Null::Value(message.render()),
+        Null::Null => Null::Null,
+        Null::Undefined => Null::Undefined
+    }
+}
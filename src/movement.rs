@@ -0,0 +1,111 @@
+use nulls::Null;
+use serde_json::Value;
+
+use crate::{message, temporal, Validator};
+
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// A position fix: latitude and longitude in degrees, and a Unix timestamp in seconds.
+struct Fix {
+    lat: f64,
+    lng: f64,
+    timestamp: f64
+}
+
+impl Fix {
+    /// Reads a fix from an object such as `{"lat": 14.5995, "lng": 120.9842, "timestamp": 1700000000}`.
+    ///
+    /// Timestamps are Unix seconds or strings accepted by `temporal::parse_instant`.
+    fn from_value(value: &Value) -> Option<Fix> {
+        let lat = value.get("lat").and_then(Value::as_f64).filter(|lat| (-90.0..=90.0).contains(lat))?;
+        let lng = value.get("lng").and_then(Value::as_f64).filter(|lng| (-180.0..=180.0).contains(lng))?;
+        let timestamp = match value.get("timestamp")? {
+            Value::String(instant) => temporal::parse_instant(instant)?.and_utc().timestamp() as f64,
+            timestamp => timestamp.as_f64()?
+        };
+
+        Some(Fix { lat, lng, timestamp })
+    }
+
+    /// Returns the great-circle distance to another fix, in meters.
+    fn distance_to(&self, other: &Fix) -> f64 {
+        let (lat_a, lat_b) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat_b - lat_a;
+        let d_lng = (other.lng - self.lng).to_radians();
+
+        let h = (d_lat / 2.0).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lng / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_M * h.sqrt().asin()
+    }
+}
+
+impl Validator {
+    /// Sets the fastest plausible speed between two position fixes.
+    ///
+    /// # Arguments
+    /// * `max_speed` - The maximum speed, in km/h, e.g. `150.0` for road vehicles.
+    pub fn set_max_speed(mut self, max_speed: f64) -> Self {
+        self.max_speed = Some(max_speed);
+        self
+    }
+
+    /// Reads the previous position fix from a named context value.
+    ///
+    /// # Arguments
+    /// * `key` - The context value's name, e.g. `last_fix`.
+    pub fn set_previous_fix_from<T>(mut self, key: T) -> Self
+    where T: ToString
+    {
+        self.previous_fix_key = Some(key.to_string());
+        self
+    }
+
+    /// Validates that the JSON value is a position fix, `{"lat", "lng", "timestamp"}`, reachable from
+    /// the previous fix in the context without exceeding the maximum speed.
+    ///
+    /// The implied speed is reported in km/h in the `speed` arg, alongside the `max` arg. A fix that is
+    /// not later than the previous one fails as `{field}-out-of-order`. The speed check is skipped when
+    /// there is no previous fix or maximum speed, since the previous fix reports its own errors.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, out of order, or implies an
+    ///   impossible speed.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_plausible_movement(&self) -> Null<String> {
+        if self.json_value.is_null() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(fix) = Fix::from_value(&self.json_value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let previous = self.previous_fix_key
+            .as_deref()
+            .and_then(|key| self.context.get(key))
+            .and_then(Fix::from_value);
+
+        let (Some(previous), Some(max)) = (previous, self.max_speed) else {
+            return Null::Undefined;
+        };
+
+        let elapsed = fix.timestamp - previous.timestamp;
+
+        if elapsed <= 0.0 {
+            return Null::Value(message::get(format!("{}-out-of-order", self.field)));
+        }
+
+        let speed = previous.distance_to(&fix) / elapsed * 3.6;
+
+        if speed > max {
+            return Null::Value(message::new(format!("{}-implausible-speed", self.field))
+                .set_args("speed", self.bound(speed.round()))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
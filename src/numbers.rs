@@ -0,0 +1,175 @@
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+const MONEY_DECIMALS: usize = 2;
+
+/// The decimal separator and accepted grouping separators of a number locale.
+struct Separators {
+    decimal: char,
+    groups: &'static [char]
+}
+
+impl Separators {
+    /// Looks up the separators of a BCP 47 locale such as `de-DE`, defaulting to `1,234.56`.
+    fn of(locale: Option<&str>) -> Self {
+        let locale = locale.unwrap_or_default().to_ascii_lowercase().replace('_', "-");
+        let mut subtags = locale.split('-');
+        let language = subtags.next().unwrap_or_default();
+        let region = subtags.next_back().unwrap_or_default();
+
+        let (decimal, groups): (char, &'static [char]) = match (language, region) {
+            ("de" | "fr" | "it", "ch") | ("de", "li") => ('.', &['\'', '’']),
+            ("es", "mx" | "us") => ('.', &[',']),
+            ("de" | "es" | "it" | "nl" | "pt" | "id" | "da" | "tr" | "el" | "ro" | "hr" | "sl" | "sr", _) => (',', &['.']),
+            ("fr" | "ru" | "pl" | "sv" | "nb" | "no" | "fi" | "cs" | "sk" | "uk" | "hu" | "bg", _) => (',', &[' ', '\u{a0}', '\u{202f}']),
+            _ => ('.', &[','])
+        };
+
+        Separators { decimal, groups }
+    }
+}
+
+/// Parses a number written with the given separators into its sign, integer digits and fraction digits.
+///
+/// Grouping is optional, but when used every group after the first must have exactly three digits.
+fn parse_number<'a>(value: &'a str, decimal: char, groups: &[char]) -> Option<(bool, String, &'a str)> {
+    let (is_negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value))
+    };
+
+    let (whole, fraction) = value.split_once(decimal).unwrap_or((value, ""));
+
+    if whole.is_empty()
+        || value.ends_with(decimal)
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let parts = whole.split(|c| groups.contains(&c)).collect::<Vec<&str>>();
+    let is_grouped = parts.len() > 1;
+
+    for (index, part) in parts.iter().enumerate() {
+        let is_sized = match (is_grouped, index) {
+            (false, _) => !part.is_empty(),
+            (true, 0) => (1..=3).contains(&part.len()),
+            (true, _) => part.len() == 3
+        };
+
+        if !is_sized || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+    }
+
+    Some((is_negative, parts.concat(), fraction))
+}
+
+impl Validator {
+    /// Sets the locale whose decimal and grouping separators numbers are written with.
+    ///
+    /// # Arguments
+    /// * `number_locale` - A BCP 47 locale such as `de-DE`, which writes `1.234,56`. Defaults to the
+    ///   `en` separators, as in `1,234.56`.
+    pub fn set_number_locale<T>(mut self, number_locale: T) -> Self
+    where T: ToString
+    {
+        self.number_locale = Some(number_locale.to_string());
+        self
+    }
+
+    /// Parses the string value as a number written in the number locale, returning it as an `f64`
+    /// within the `fmin`/`fmax` bounds.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<f64>)` - The number, or `0.0` for an empty optional value, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, uses the wrong separators, is not a number,
+    ///   or is out of bounds.
+    pub fn validate_parse_f64(&self) -> Result<Valid<f64>, Error> {
+        let (outcome, value) = self.parse_localized(None);
+        self.to_valid(outcome, value.parse().unwrap_or_default())
+    }
+
+    /// Parses the string value as a money amount written in the number locale, returning it in the
+    /// canonical `1234.56` form so it can be handed to a decimal type without loss.
+    ///
+    /// Amounts may have up to `max_decimals` fractional digits, defaulting to 2.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<String>)` - The canonical amount, or an empty string for an empty optional value,
+    ///   if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, uses the wrong separators, is not a number,
+    ///   is out of bounds, or has too many fractional digits.
+    pub fn validate_money_string(&self) -> Result<Valid<String>, Error> {
+        let (outcome, value) = self.parse_localized(Some(self.max_decimals.unwrap_or(MONEY_DECIMALS)));
+        self.to_valid(outcome, value)
+    }
+
+    /// Parses and checks a localized number, returning the first failure alongside the number in
+    /// canonical form.
+    fn parse_localized(&self, max_decimals: Option<usize>) -> (Null<String>, String) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), String::new()),
+                false => (Null::Undefined, String::new())
+            };
+        }
+
+        let separators = Separators::of(self.number_locale.as_deref());
+
+        let Some((is_negative, whole, fraction)) = parse_number(value, separators.decimal, separators.groups) else {
+            // A number that only parses with other separators was written for another locale
+            let is_foreign = [('.', &[','][..]), (',', &['.'][..]), (',', &[' '][..])]
+                .iter()
+                .any(|(decimal, groups)| parse_number(value, *decimal, groups).is_some());
+
+            let key = match is_foreign {
+                true => "separator",
+                false => "invalid"
+            };
+
+            return (Null::Value(message::new(format!("{}-{}", self.field, key))
+                .set_args("decimal", separators.decimal)
+                .set_args("group", separators.groups[0])
+                .build()), String::new());
+        };
+
+        let canonical = match (is_negative, fraction.is_empty()) {
+            (true, true) => format!("-{}", whole),
+            (true, false) => format!("-{}.{}", whole, fraction),
+            (false, true) => whole,
+            (false, false) => format!("{}.{}", whole, fraction)
+        };
+
+        let number = canonical.parse::<f64>().unwrap_or_default();
+
+        if let Some(min) = self.fmin
+            && number < min
+        {
+            return (Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build()), canonical);
+        }
+
+        if let Some(max) = self.fmax
+            && number > max
+        {
+            return (Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build()), canonical);
+        }
+
+        if let Some(max_decimals) = max_decimals
+            && fraction.len() > max_decimals
+        {
+            return (Null::Value(message::new(format!("{}-decimals", self.field))
+                .set_args("max", max_decimals)
+                .build()), canonical);
+        }
+
+        (Null::Undefined, canonical)
+    }
+}
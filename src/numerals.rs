@@ -0,0 +1,152 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+const ROMAN: [(usize, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+    (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+    (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")
+];
+
+/// Parses a roman numeral in canonical subtractive form, from `I` to `MMMCMXCIX`.
+fn parse_roman(value: &str) -> Option<usize> {
+    let mut rest = value;
+    let mut number = 0;
+
+    for (amount, numeral) in ROMAN {
+        while let Some(tail) = rest.strip_prefix(numeral) {
+            rest = tail;
+            number += amount;
+        }
+    }
+
+    // Round-tripping rejects non-canonical forms such as `IIII` or `VX`
+    match rest.is_empty() && (1..=3999).contains(&number) && to_roman(number) == value {
+        true => Some(number),
+        false => None
+    }
+}
+
+/// Formats a number from 1 to 3999 as a roman numeral.
+fn to_roman(mut number: usize) -> String {
+    let mut roman = String::new();
+
+    for (amount, numeral) in ROMAN {
+        while number >= amount {
+            roman.push_str(numeral);
+            number -= amount;
+        }
+    }
+
+    roman
+}
+
+/// Returns the English ordinal suffix of a number, e.g. `st` for 21 and `th` for 11.
+fn ordinal_suffix(number: u64) -> &'static str {
+    match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th"
+    }
+}
+
+impl Validator {
+    /// Validates that the string value is a roman numeral within the minimum/maximum values.
+    ///
+    /// Numerals must use the canonical subtractive form, so `IV` is accepted but `IIII` is not, and
+    /// range from `I` to `MMMCMXCIX`. Lowercase is accepted unless the validator is case sensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a canonical roman numeral, or out of bounds.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_roman_numeral(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_ascii_uppercase()
+        };
+
+        let Some(number) = parse_roman(&value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        self.validate_numeral_bounds(number)
+    }
+
+    /// Validates that the string value is an English ordinal such as `1st`, `22nd` or `113th`, within
+    /// the minimum/maximum values.
+    ///
+    /// A number with the wrong suffix, such as `2th`, reports the expected suffix in the `suffix` arg.
+    /// Suffixes are case-insensitive unless the validator is case sensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not an ordinal, has the wrong suffix, or is out of bounds.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ordinal_suffix(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+        let (digits, suffix) = value.split_at(split);
+
+        let number = match digits.starts_with('0') {
+            true => None,
+            false => digits.parse::<u64>().ok()
+        };
+
+        let Some(number) = number.filter(|_| suffix.len() == 2 && suffix.bytes().all(|byte| byte.is_ascii_alphabetic())) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let expected = ordinal_suffix(number);
+        let is_expected = match self.is_case_sensitive {
+            true => suffix == expected,
+            false => suffix.eq_ignore_ascii_case(expected)
+        };
+
+        if !is_expected {
+            return Null::Value(message::new(format!("{}-suffix", self.field))
+                .set_args("suffix", expected)
+                .build());
+        }
+
+        self.validate_numeral_bounds(usize::try_from(number).unwrap_or(usize::MAX))
+    }
+
+    /// Checks a parsed numeral against the minimum/maximum values.
+    fn validate_numeral_bounds(&self, number: usize) -> Null<String> {
+        if let Some(min) = self.min
+            && number < min
+        {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if let Some(max) = self.max
+            && number > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,109 @@
+use nulls::Null;
+
+use crate::{message, Error, Message, Valid, Validator};
+
+/// What to do when a single-valued field arrives more than once, e.g. `?sort=name&sort=date`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Keeps the first occurrence.
+    First,
+    /// Keeps the last occurrence.
+    Last,
+    /// Rejects the field with a `{field}-duplicate` error.
+    Error
+}
+
+impl Validator {
+    /// Sets the values a field arrived with, one per occurrence of its key in a form or query string.
+    ///
+    /// # Arguments
+    /// * `occurrences` - A slice of the values, in their original order.
+    pub fn set_occurrences<T>(mut self, occurrences: &[T]) -> Self
+    where T: ToString
+    {
+        self.occurrences = occurrences.iter().map(|value| value.to_string()).collect();
+        self
+    }
+
+    /// Sets the minimum number of times the field must occur.
+    ///
+    /// # Arguments
+    /// * `min_occurrences` - The minimum count.
+    pub fn set_min_occurrences(mut self, min_occurrences: usize) -> Self {
+        self.min_occurrences = Some(min_occurrences);
+        self
+    }
+
+    /// Sets the maximum number of times the field may occur.
+    ///
+    /// # Arguments
+    /// * `max_occurrences` - The maximum count.
+    pub fn set_max_occurrences(mut self, max_occurrences: usize) -> Self {
+        self.max_occurrences = Some(max_occurrences);
+        self
+    }
+
+    /// Treats the field as single-valued, resolving repeated occurrences with the given policy.
+    ///
+    /// # Arguments
+    /// * `duplicate_policy` - Whether to keep the first or last occurrence, or reject the field.
+    pub fn set_duplicate_policy(mut self, duplicate_policy: DuplicatePolicy) -> Self {
+        self.duplicate_policy = Some(duplicate_policy);
+        self
+    }
+
+    /// Validates how many times the field occurred, returning the occurrences to use.
+    ///
+    /// The count is checked against the minimum/maximum occurrences first. Without a duplicate policy
+    /// every occurrence is kept; with one, the field is single-valued and at most one is kept.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Vec<String>>)` - The occurrences to use, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but absent, occurs too few or too many times, or
+    ///   repeats under `DuplicatePolicy::Error`.
+    pub fn validate_occurrences(&self) -> Result<Valid<Vec<String>>, Error> {
+        let (outcome, occurrences) = self.parse_occurrences();
+        self.to_valid(message::render(outcome), occurrences)
+    }
+
+    /// Checks the occurrence count and applies the duplicate policy.
+    pub(crate) fn parse_occurrences(&self) -> (Null<Message>, Vec<String>) {
+        let count = self.occurrences.len();
+
+        if count == 0 && self.is_required {
+            return (Null::Value(message::key(format!("{}-empty", self.field))), Vec::new());
+        }
+
+        if let Some(min) = self.min_occurrences
+            && count < min
+        {
+            return (Null::Value(message::new(format!("{}-min-occurrences", self.field))
+                .set_args("min", self.bound(min))
+                .set_args("count", count)
+                .into_message()), Vec::new());
+        }
+
+        if let Some(max) = self.max_occurrences
+            && count > max
+        {
+            return (Null::Value(message::new(format!("{}-max-occurrences", self.field))
+                .set_args("max", self.bound(max))
+                .set_args("count", count)
+                .into_message()), Vec::new());
+        }
+
+        let kept = match self.duplicate_policy {
+            None => self.occurrences.clone(),
+            Some(DuplicatePolicy::First) => self.occurrences.first().cloned().into_iter().collect(),
+            Some(DuplicatePolicy::Last) => self.occurrences.last().cloned().into_iter().collect(),
+            Some(DuplicatePolicy::Error) if count > 1 => {
+                return (Null::Value(message::new(format!("{}-duplicate", self.field))
+                    .set_args("count", count)
+                    .into_message()), Vec::new());
+            },
+            Some(DuplicatePolicy::Error) => self.occurrences.clone()
+        };
+
+        (Null::Undefined, kept)
+    }
+}
@@ -0,0 +1,232 @@
+use nulls::Null;
+#[cfg(feature = "regex")]
+use regex::RegexBuilder;
+
+use crate::{message, Validator};
+
+#[cfg(feature = "regex")]
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+impl Validator {
+    /// Sets the maximum compiled size, in bytes, of user-supplied regular expressions.
+    ///
+    /// # Arguments
+    /// * `regex_size_limit` - The compiled size limit. Defaults to 1 MiB.
+    pub fn set_regex_size_limit(mut self, regex_size_limit: usize) -> Self {
+        self.regex_size_limit = Some(regex_size_limit);
+        self
+    }
+
+    /// Validates that the string value is a valid, reasonably safe regular expression.
+    ///
+    /// Patterns longer than `max`, patterns with nested quantifiers such as `(a+)+` (catastrophic in
+    /// backtracking engines), and patterns whose compiled form exceeds the size limit are rejected.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too long, unsafe, too complex, not a valid pattern, or times out.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "regex")]
+    pub fn validate_regex_pattern(&self) -> Null<String> {
+        if self.timeout.is_some() {
+            return self.validate_with_timeout(Validator::validate_regex_pattern);
+        }
+
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(max) = self.max
+            && self.string_value.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if has_nested_quantifier(&self.string_value) {
+            return Null::Value(message::get(format!("{}-unsafe", self.field)));
+        }
+
+        let limit = self.regex_size_limit.unwrap_or(REGEX_SIZE_LIMIT);
+
+        match RegexBuilder::new(&self.string_value).size_limit(limit).build() {
+            Ok(_) => Null::Undefined,
+            Err(regex::Error::CompiledTooBig(_)) => {
+                Null::Value(message::get(format!("{}-too-complex", self.field)))
+            },
+            Err(_) => Null::Value(message::get(format!("{}-invalid", self.field)))
+        }
+    }
+
+    /// Configures whether glob patterns must be relative and free of `..` segments.
+    ///
+    /// # Arguments
+    /// * `is_relative_only` - A boolean indicating if absolute or traversing patterns are rejected.
+    pub fn set_as_relative_only(mut self, is_relative_only: bool) -> Self {
+        self.is_relative_only = is_relative_only;
+        self
+    }
+
+    /// Validates that the string value is a syntactically valid glob pattern.
+    ///
+    /// Character classes and `{a,b}` alternations must be balanced, escapes must be complete, and
+    /// `**` must form a whole path segment. With `is_relative_only`, absolute patterns and `..`
+    /// segments are rejected as well.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too long, malformed, absolute, or traversing.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_glob(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(max) = self.max
+            && self.string_value.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if !is_valid_glob(&self.string_value) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if self.is_relative_only {
+            let value = &self.string_value;
+            let bytes = value.as_bytes();
+            let is_absolute = value.starts_with('/')
+                || value.starts_with('\\')
+                || (bytes.len() > 1 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':');
+
+            if is_absolute {
+                return Null::Value(message::get(format!("{}-absolute", self.field)));
+            }
+
+            if value.split(['/', '\\']).any(|segment| segment == "..") {
+                return Null::Value(message::get(format!("{}-traversal", self.field)));
+            }
+        }
+
+        Null::Undefined
+    }
+}
+
+/// Checks glob syntax: complete escapes, closed character classes, balanced alternations and
+/// `**` used only as a whole path segment.
+fn is_valid_glob(pattern: &str) -> bool {
+    let chars = pattern.chars().collect::<Vec<char>>();
+    let mut braces = 0usize;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                if i + 1 >= chars.len() {
+                    return false;
+                }
+
+                i += 1;
+            },
+            '[' => {
+                i += 1;
+
+                if matches!(chars.get(i), Some('!' | '^')) {
+                    i += 1;
+                }
+
+                // A leading `]` is a literal member of the class
+                if chars.get(i) == Some(&']') {
+                    i += 1;
+                }
+
+                while i < chars.len() && chars[i] != ']' {
+                    if chars[i] == '/' {
+                        return false;
+                    }
+
+                    i += 1;
+                }
+
+                if i >= chars.len() {
+                    return false;
+                }
+            },
+            '{' => braces += 1,
+            '}' => {
+                if braces == 0 {
+                    return false;
+                }
+
+                braces -= 1;
+            },
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let is_segment_start = i == 0 || chars[i - 1] == '/';
+                let is_segment_end = matches!(chars.get(i + 2), None | Some('/'));
+
+                if !is_segment_start || !is_segment_end {
+                    return false;
+                }
+
+                i += 1;
+            },
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    braces == 0
+}
+
+/// Detects a quantified group that itself contains a quantifier, e.g. `(a+)+` or `(\w*)*`.
+#[cfg(feature = "regex")]
+fn has_nested_quantifier(pattern: &str) -> bool {
+    let chars = pattern.chars().collect::<Vec<char>>();
+    let mut groups = vec![false];
+    let mut in_class = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            _ if in_class => {},
+            '(' => groups.push(false),
+            ')' => {
+                let has_quantifier = groups.pop().unwrap_or(false);
+                let is_quantified = matches!(chars.get(i + 1), Some('*' | '+' | '{'));
+
+                if has_quantifier && is_quantified {
+                    return true;
+                }
+
+                if groups.is_empty() {
+                    groups.push(false);
+                }
+
+                if let Some(outer) = groups.last_mut() {
+                    *outer |= has_quantifier;
+                }
+            },
+            '*' | '+' | '{' => {
+                if let Some(current) = groups.last_mut() {
+                    *current = true;
+                }
+            },
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    false
+}
@@ -0,0 +1,155 @@
+use nulls::Null;
+#[cfg(feature = "phone")]
+use phonenumber::{country, Mode};
+
+#[cfg(feature = "phone")]
+use crate::{Error, PhoneNumber, Valid};
+use crate::{message, Validator};
+
+const EXTENSION_MAX: usize = 10;
+
+/// Splits a phone number into the number and its extension, if any.
+///
+/// Recognizes RFC 3966 `;ext=1234` as well as the common `ext. 1234`, `ext 1234`, `x1234` and `#1234`
+/// suffixes. The extension is only split off when it consists of digits.
+///
+/// # Arguments
+/// * `value` - The phone number, possibly with an extension suffix.
+pub fn split_phone_extension(value: &str) -> (&str, Option<&str>) {
+    let lower = value.to_ascii_lowercase();
+
+    for marker in [";ext=", "ext.", "ext", "x", "#"] {
+        if let Some(index) = lower.rfind(marker) {
+            let extension = value[index + marker.len()..].trim();
+
+            if !extension.is_empty() && extension.chars().all(|c| c.is_ascii_digit()) {
+                let number = value[..index].trim_end().trim_end_matches([',', ';']).trim_end();
+                return (number, Some(extension));
+            }
+        }
+    }
+
+    (value.trim(), None)
+}
+
+impl Validator {
+    /// Sets the region national phone numbers are read in, e.g. `PH` for `0917 123 4567`.
+    ///
+    /// # Arguments
+    /// * `default_region` - An ISO 3166-1 alpha-2 region code.
+    pub fn set_default_region<T>(mut self, default_region: T) -> Self
+    where T: ToString
+    {
+        self.default_region = Some(default_region.to_string().to_ascii_uppercase());
+        self
+    }
+
+    /// Validates that the string value is a valid phone number for its region.
+    ///
+    /// Numbers in international form, e.g. `+63 917 123 4567`, are accepted from any region. Numbers
+    /// in national form are read in the default region, and fail without one. An extension suffix such
+    /// as `;ext=1234` or `x1234` is split off and checked like `validate_phone_extension`, reporting
+    /// through `{field}-extension-invalid`, `{field}-extension-min` and `{field}-extension-max`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, the number is malformed or not valid for
+    ///   its region, or the extension is invalid.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "phone")]
+    pub fn validate_phone(&self) -> Null<String> {
+        self.parse_phone().0
+    }
+
+    /// Validates the string value like `validate_phone`, returning it normalized to E.164 form,
+    /// e.g. `+639171234567`, followed by any extension as `;ext=1234`.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<PhoneNumber>)` - The normalized number, or an empty one for an empty optional
+    ///   value, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, the number is malformed or not valid for
+    ///   its region, or the extension is invalid.
+    #[cfg(feature = "phone")]
+    pub fn validate_phone_typed(&self) -> Result<Valid<PhoneNumber>, Error> {
+        let (outcome, number) = self.parse_phone();
+        self.to_valid(outcome, PhoneNumber::new(number))
+    }
+
+    /// Parses the phone number, returning the outcome alongside its E.164 form.
+    #[cfg(feature = "phone")]
+    fn parse_phone(&self) -> (Null<String>, String) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), String::new()),
+                false => (Null::Undefined, String::new())
+            };
+        }
+
+        let (value, extension) = split_phone_extension(value);
+
+        if let Some(extension) = extension
+            && let Null::Value(error) = self.check_extension(extension, &format!("{}-extension", self.field))
+        {
+            return (Null::Value(error), String::new());
+        }
+
+        let region = self.default_region.as_deref().and_then(|region| region.parse::<country::Id>().ok());
+
+        match phonenumber::parse(region, value) {
+            Ok(number) if phonenumber::is_valid(&number) => {
+                let number = number.format().mode(Mode::E164).to_string();
+
+                match extension {
+                    Some(extension) => (Null::Undefined, format!("{};ext={}", number, extension)),
+                    None => (Null::Undefined, number)
+                }
+            },
+            _ => (Null::Value(message::get(format!("{}-invalid", self.field))), String::new())
+        }
+    }
+
+    /// Validates that the string value is a phone extension made of digits.
+    ///
+    /// The length is bounded by `min` and `max`, with `max` defaulting to 10 digits.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, contains non-digits, or violates the length constraints.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_phone_extension(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        self.check_extension(&self.string_value, &self.field)
+    }
+
+    /// Checks an extension's digits and length, reporting through `{key}-invalid`, `{key}-min` and `{key}-max`.
+    fn check_extension(&self, extension: &str, key: &str) -> Null<String> {
+        if !extension.chars().all(|c| c.is_ascii_digit()) {
+            return Null::Value(message::get(format!("{}-invalid", key)));
+        }
+
+        let len = extension.len();
+        let max = self.max.unwrap_or(EXTENSION_MAX);
+
+        if let Some(min) = self.min
+            && len < min
+        {
+            return Null::Value(message::new(format!("{}-min", key))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if len > max {
+            return Null::Value(message::new(format!("{}-max", key))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
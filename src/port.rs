@@ -0,0 +1,70 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+const PORT_MAX: usize = 65535;
+const WELL_KNOWN_MAX: usize = 1023;
+
+impl Validator {
+    /// Configures whether well-known ports, below 1024, are rejected.
+    ///
+    /// # Arguments
+    /// * `is_well_known_excluded` - A boolean indicating if ports 1 to 1023 are rejected.
+    pub fn set_as_well_known_excluded(mut self, is_well_known_excluded: bool) -> Self {
+        self.is_well_known_excluded = is_well_known_excluded;
+        self
+    }
+
+    /// Validates that the string value, or the `i32` value when the string is empty, is a port number
+    /// from 1 to 65535.
+    ///
+    /// The minimum/maximum constraints narrow the range further. Ports below the range fail with the
+    /// `{field}-min` message and above it with `{field}-max`, including when well-known ports are excluded.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a number, or outside the allowed range.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_port(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        let port = match (value.is_empty(), self.i32_value) {
+            (false, _) => match value.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => value.parse::<usize>().ok().or(Some(usize::MAX)),
+                false => None
+            },
+            (true, Some(port)) => Some(usize::try_from(port).unwrap_or_default()),
+            (true, None) => {
+                return match self.is_required {
+                    true => Null::Value(message::get(format!("{}-empty", self.field))),
+                    false => Null::Undefined
+                };
+            }
+        };
+
+        let Some(port) = port else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let floor = match self.is_well_known_excluded {
+            true => WELL_KNOWN_MAX + 1,
+            false => 1
+        };
+
+        let min = self.min.unwrap_or_default().max(floor);
+        let max = self.max.unwrap_or(PORT_MAX).min(PORT_MAX);
+
+        if port < min {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if port > max {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
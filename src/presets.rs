@@ -0,0 +1,143 @@
+//! Curated option lists for use with `set_option_list` and friends.
+//!
+//! Presets are plain static slices, so they can be passed straight to the option setters:
+//!
+//! ```ignore
+//! new("title").set_string_value(&title).set_option_list(presets::honorifics()).validate_list_options();
+//! ```
+
+#[cfg(feature = "healthcare")]
+pub mod healthcare;
+#[cfg(feature = "travel")]
+pub mod travel;
+
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+/// Common English honorifics, without trailing periods.
+pub fn honorifics() -> &'static [&'static str] {
+    &["Mr", "Mrs", "Ms", "Miss", "Mx", "Dr", "Prof", "Rev", "Hon", "Sir", "Dame"]
+}
+
+/// ISO/IEC 5218 codes for the representation of human sexes.
+///
+/// `0` = not known, `1` = male, `2` = female, `9` = not applicable.
+pub fn iso5218() -> &'static [&'static str] {
+    &["0", "1", "2", "9"]
+}
+
+/// Lowercase gender identity options for self-described profile fields.
+pub fn genders() -> &'static [&'static str] {
+    &["female", "male", "non-binary", "other", "prefer-not-to-say"]
+}
+
+/// Active ISO 4217 currency codes, excluding precious metals and testing codes.
+pub fn currencies() -> &'static [&'static str] {
+    &[
+        "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN", "BHD",
+        "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHF", "CLP",
+        "CNY", "COP", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR",
+        "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF",
+        "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW",
+        "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+        "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK",
+        "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB",
+        "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC",
+        "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD",
+        "UYU", "UZS", "VES", "VND", "VUV", "WST", "XAF", "XCD", "XOF", "XPF", "YER", "ZAR", "ZMW", "ZWG"
+    ]
+}
+
+/// The CSS named colors, lowercase, from CSS Color Module Level 4.
+///
+/// `transparent` and `currentcolor` are keywords rather than named colors, so they are not included.
+pub fn css_colors() -> &'static [&'static str] {
+    &[
+        "aliceblue", "antiquewhite", "aqua", "aquamarine", "azure", "beige", "bisque", "black",
+        "blanchedalmond", "blue", "blueviolet", "brown", "burlywood", "cadetblue", "chartreuse",
+        "chocolate", "coral", "cornflowerblue", "cornsilk", "crimson", "cyan", "darkblue", "darkcyan",
+        "darkgoldenrod", "darkgray", "darkgreen", "darkgrey", "darkkhaki", "darkmagenta",
+        "darkolivegreen", "darkorange", "darkorchid", "darkred", "darksalmon", "darkseagreen",
+        "darkslateblue", "darkslategray", "darkslategrey", "darkturquoise", "darkviolet", "deeppink",
+        "deepskyblue", "dimgray", "dimgrey", "dodgerblue", "firebrick", "floralwhite", "forestgreen",
+        "fuchsia", "gainsboro", "ghostwhite", "gold", "goldenrod", "gray", "green", "greenyellow",
+        "grey", "honeydew", "hotpink", "indianred", "indigo", "ivory", "khaki", "lavender",
+        "lavenderblush", "lawngreen", "lemonchiffon", "lightblue", "lightcoral", "lightcyan",
+        "lightgoldenrodyellow", "lightgray", "lightgreen", "lightgrey", "lightpink", "lightsalmon",
+        "lightseagreen", "lightskyblue", "lightslategray", "lightslategrey", "lightsteelblue",
+        "lightyellow", "lime", "limegreen", "linen", "magenta", "maroon", "mediumaquamarine",
+        "mediumblue", "mediumorchid", "mediumpurple", "mediumseagreen", "mediumslateblue",
+        "mediumspringgreen", "mediumturquoise", "mediumvioletred", "midnightblue", "mintcream",
+        "mistyrose", "moccasin", "navajowhite", "navy", "oldlace", "olive", "olivedrab", "orange",
+        "orangered", "orchid", "palegoldenrod", "palegreen", "paleturquoise", "palevioletred",
+        "papayawhip", "peachpuff", "peru", "pink", "plum", "powderblue", "purple", "rebeccapurple",
+        "red", "rosybrown", "royalblue", "saddlebrown", "salmon", "sandybrown", "seagreen", "seashell",
+        "sienna", "silver", "skyblue", "slateblue", "slategray", "slategrey", "snow", "springgreen",
+        "steelblue", "tan", "teal", "thistle", "tomato", "turquoise", "violet", "wheat", "white",
+        "whitesmoke", "yellow", "yellowgreen"
+    ]
+}
+
+/// Log levels, lowercase, from most to least verbose.
+pub fn log_levels() -> &'static [&'static str] {
+    &["trace", "debug", "info", "warn", "error"]
+}
+
+/// Common English profanity and slurs, uppercase, for screening generated or user-chosen codes.
+///
+/// Words that commonly occur inside harmless words (such as `ASS` in `CLASS` or `RAPE` in `GRAPE`) are left out.
+pub fn profanity() -> &'static [&'static str] {
+    &[
+        "BITCH", "BOLLOCK", "BONER", "BOOB", "CLIT", "COCK", "CUNT", "DICK", "DILDO", "DYKE", "FAGGOT",
+        "FUCK", "JIZZ", "KIKE", "NAZI", "NIGGA", "NIGGER", "PENIS", "PISS", "PORN", "PUSSY", "RETARD",
+        "SHIT", "SLUT", "TITS", "TWAT", "VAGINA", "WANK", "WHORE"
+    ]
+}
+
+/// Sane ranges for health and sensor readings, as `(name, canonical unit, min, max)`, for use with
+/// `set_measurement_preset`.
+///
+/// The bounds are wide enough to admit extreme but survivable vitals and to reject sensor faults and
+/// typos, such as a body temperature of `375` for `37.5`.
+pub fn measurements() -> &'static [(&'static str, &'static str, f64, f64)] {
+    &[
+        ("body-temperature", "°C", 30.0, 45.0),
+        ("heart-rate", "bpm", 20.0, 250.0),
+        ("respiratory-rate", "breaths/min", 4.0, 70.0),
+        ("oxygen-saturation", "%", 50.0, 100.0),
+        ("systolic-pressure", "mmHg", 50.0, 260.0),
+        ("diastolic-pressure", "mmHg", 20.0, 160.0),
+        ("body-weight", "kg", 0.2, 650.0),
+        ("body-height", "cm", 20.0, 280.0),
+        ("air-temperature", "°C", -90.0, 60.0),
+        ("relative-humidity", "%", 0.0, 100.0),
+        ("barometric-pressure", "hPa", 870.0, 1085.0)
+    ]
+}
+
+impl Validator {
+    /// Validates that the string value is one of `log_levels()`, returning it in its canonical
+    /// lowercase form so that `WARN` and `Warn` are both stored as `warn`.
+    ///
+    /// Levels are always matched case-insensitively. An unknown level reports the levels in the `options` arg.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<String>)` - The canonical level, or an empty string for an empty optional value, if
+    ///   the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, or the value is not a log level.
+    pub fn validate_log_level(&self) -> Result<Valid<String>, Error> {
+        let value = self.string_value.trim();
+
+        let outcome = match log_levels().iter().find(|level| level.eq_ignore_ascii_case(value)) {
+            Some(level) => return self.to_valid(Null::Undefined, level.to_string()),
+            None if value.is_empty() && self.is_required => Null::Value(message::get(format!("{}-empty", self.field))),
+            None if value.is_empty() => Null::Undefined,
+            None => Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("options", self.format_options(log_levels()))
+                .build())
+        };
+
+        self.to_valid(outcome, String::new())
+    }
+}
@@ -0,0 +1,67 @@
+//! Health-tech presets: blood types, clinical unit strings and ICD-10 code format validation.
+
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// ABO/Rh blood types.
+pub fn blood_types() -> &'static [&'static str] {
+    &["A+", "A-", "B+", "B-", "AB+", "AB-", "O+", "O-"]
+}
+
+/// Common clinical unit strings, in their conventional casing.
+pub fn units() -> &'static [&'static str] {
+    &[
+        "mg", "g", "kg", "mcg", "µg", "ng", "mL", "L", "dL", "IU", "U/L", "mmol/L", "µmol/L", "mg/dL",
+        "g/dL", "mEq/L", "mmHg", "bpm", "breaths/min", "°C", "°F", "%", "mm", "cm", "m", "kg/m2"
+    ]
+}
+
+/// Checks whether a code follows the ICD-10 (and ICD-10-CM) format, e.g. `E11.9` or `S72001A`.
+///
+/// # Arguments
+/// * `code` - The code to check; the dot after the category is optional.
+pub fn is_icd10(code: &str) -> bool {
+    let chars = code.chars().collect::<Vec<char>>();
+
+    if chars.len() < 3 {
+        return false;
+    }
+
+    let is_category = chars[0].is_ascii_uppercase()
+        && chars[1].is_ascii_digit()
+        && (chars[2].is_ascii_digit() || chars[2].is_ascii_uppercase());
+
+    let subcategory = match chars.get(3) {
+        Some('.') => &chars[4..],
+        _ => &chars[3..]
+    };
+
+    is_category
+        && (chars.len() == 3 || (1..=4).contains(&subcategory.len()))
+        && subcategory.iter().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+impl Validator {
+    /// Validates that the string value is a well-formed ICD-10 diagnosis code.
+    ///
+    /// Only the format is checked; the code is not looked up in a classification release.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or if the code is malformed.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_icd10(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if !is_icd10(&self.string_value) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
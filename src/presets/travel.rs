@@ -0,0 +1,45 @@
+//! Travel presets: IATA codes of major commercial airports.
+
+/// IATA codes of major commercial airports with scheduled passenger service.
+///
+/// The list covers the busiest airports in each region rather than every IATA location; pass a full
+/// dataset to `set_option_list` when smaller airports must be accepted as well.
+pub fn airports() -> &'static [&'static str] {
+    &[
+        // North America
+        "ABQ", "ANC", "ATL", "AUS", "BDL", "BNA", "BOS", "BUF", "BWI", "CLE", "CLT", "CMH", "CUN", "CVG",
+        "DAL", "DCA", "DEN", "DFW", "DTW", "EWR", "FLL", "GDL", "HNL", "HOU", "IAD", "IAH", "IND", "JAX",
+        "JFK", "LAS", "LAX", "LGA", "MCI", "MCO", "MDW", "MEM", "MEX", "MIA", "MKE", "MSP", "MSY", "MTY",
+        "OAK", "OGG", "OKC", "OMA", "ONT", "ORD", "PBI", "PDX", "PHL", "PHX", "PIT", "PVR", "RDU", "RIC",
+        "RNO", "RSW", "SAN", "SAT", "SDF", "SEA", "SFO", "SJC", "SJD", "SJU", "SLC", "SMF", "SNA", "STL",
+        "TIJ", "TPA", "TUL", "TUS", "YEG", "YHZ", "YOW", "YUL", "YVR", "YWG", "YYC", "YYZ",
+        // Central America and the Caribbean
+        "BZE", "GUA", "MBJ", "NAS", "PTY", "PUJ", "SAL", "SDQ", "SJO", "SXM", "AUA", "BGI", "HAV", "KIN",
+        "POS",
+        // South America
+        "AEP", "ASU", "BOG", "BSB", "CCS", "CLO", "CNF", "COR", "CWB", "EZE", "FOR", "GIG", "GRU", "GYE",
+        "LIM", "MDE", "MVD", "POA", "REC", "SCL", "SSA", "UIO", "VCP", "VVI",
+        // Europe
+        "AGP", "ALC", "AMS", "ARN", "ATH", "AYT", "BCN", "BEG", "BER", "BGO", "BHX", "BIO", "BLQ", "BRS",
+        "BRU", "BSL", "BUD", "CDG", "CGN", "CPH", "CTA", "DUB", "DUS", "EDI", "FAO", "FCO", "FRA", "GLA",
+        "GVA", "HAM", "HEL", "IBZ", "IST", "KEF", "KRK", "KBP", "LCA", "LGW", "LHR", "LIS", "LJU", "LPA",
+        "LTN", "LYS", "MAD", "MAN", "MLA", "MRS", "MUC", "MXP", "NAP", "NCE", "OPO", "ORY", "OSL", "OTP",
+        "PMI", "PRG", "RIX", "SAW", "SOF", "STN", "STR", "SVO", "SVQ", "TFS", "TLL", "TLS", "TRN",
+        "VCE", "VIE", "VLC", "VNO", "WAW", "ZAG", "ZRH",
+        // Middle East
+        "AMM", "AUH", "BAH", "BEY", "DMM", "DOH", "DWC", "DXB", "IKA", "JED", "KWI", "MCT", "MED", "RUH",
+        "SHJ", "TLV",
+        // Africa
+        "ABJ", "ACC", "ADD", "ALG", "CAI", "CMN", "CPT", "DAR", "DKR", "DSS", "DUR", "EBB", "HBE", "HRG",
+        "JNB", "KGL", "LOS", "LAD", "MRU", "NBO", "RAK", "SEZ", "SSH", "TUN", "ABV", "ZNZ",
+        // Asia
+        "ALA", "AMD", "BKK", "BLR", "BOM", "CAN", "CCU", "CEB", "CGK", "CJU", "CKG", "CMB", "COK", "CSX",
+        "CTU", "DAC", "DEL", "DMK", "DPS", "FUK", "GMP", "GOI", "HAN", "HGH", "HKG", "HKT", "HND", "HYD",
+        "ICN", "ISB", "ITM", "KHI", "KIX", "KMG", "KTM", "KUL", "LHE", "MAA", "MFM", "MLE", "MNL", "NGO",
+        "NKG", "NRT", "PEK", "PEN", "PKX", "PNH", "PVG", "REP", "SGN", "SHA", "SIN", "SZX", "TAS", "TPE",
+        "TSA", "ULN", "WUH", "XIY", "XMN",
+        // Oceania
+        "ADL", "AKL", "BNE", "CBR", "CHC", "CNS", "DRW", "HBA", "MEL", "NAN", "OOL", "PER", "PPT", "SYD",
+        "WLG"
+    ]
+}
@@ -0,0 +1,115 @@
+use nulls::Null;
+
+use crate::{message, presets, Validator};
+
+const PROMO_MIN: usize = 4;
+const PROMO_MAX: usize = 32;
+
+/// Maps common digit-for-letter substitutions back to letters, e.g. `5H1T` to `SHIT`.
+fn unleet(c: char) -> char {
+    match c {
+        '0' => 'O',
+        '1' => 'I',
+        '3' => 'E',
+        '4' => 'A',
+        '5' => 'S',
+        '7' => 'T',
+        '8' => 'B',
+        c => c
+    }
+}
+
+impl Validator {
+    /// Sets the prefix codes must start with, such as a campaign identifier.
+    ///
+    /// # Arguments
+    /// * `required_prefix` - The prefix.
+    pub fn set_required_prefix<T>(mut self, required_prefix: T) -> Self
+    where T: ToString
+    {
+        self.required_prefix = Some(required_prefix.to_string());
+        self
+    }
+
+    /// Sets the words codes must not contain, replacing `presets::profanity()`.
+    ///
+    /// # Arguments
+    /// * `blocked_words` - A slice of blocked words.
+    pub fn set_blocked_words<T>(mut self, blocked_words: &[T]) -> Self
+    where T: ToString
+    {
+        self.blocked_words = Some(blocked_words.iter().map(|word| word.to_string()).collect::<Vec<String>>());
+        self
+    }
+
+    /// Validates that the string value is a promo code: uppercase letters and digits within the
+    /// length bounds, starting with the required prefix and free of blocked words.
+    ///
+    /// Lengths default to 4 through 32 characters. Lowercase is accepted unless the validator is case
+    /// sensitive. Blocked words default to `presets::profanity()` and are also caught when spelled
+    /// with lookalike digits, such as `5H1T`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too short or long, contains other
+    ///   characters, lacks the prefix, or contains a blocked word.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_promo_code(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_ascii_uppercase()
+        };
+
+        let min = self.min.unwrap_or(PROMO_MIN);
+        let max = self.max.unwrap_or(PROMO_MAX);
+
+        if value.len() < min {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if value.len() > max {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if !value.bytes().all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit()) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let prefix = self.required_prefix.as_deref().map(|prefix| match self.is_case_sensitive {
+            true => prefix.to_string(),
+            false => prefix.to_ascii_uppercase()
+        });
+
+        if let Some(prefix) = prefix
+            && !value.starts_with(&prefix)
+        {
+            return Null::Value(message::new(format!("{}-prefix", self.field))
+                .set_args("prefix", prefix)
+                .build());
+        }
+
+        let letters = value.chars().map(unleet).collect::<String>();
+        let is_blocked = match &self.blocked_words {
+            Some(words) => words.iter().any(|word| letters.contains(&word.to_ascii_uppercase())),
+            None => presets::profanity().iter().any(|word| letters.contains(word))
+        };
+
+        if is_blocked {
+            return Null::Value(message::get(format!("{}-blocked", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
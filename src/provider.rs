@@ -0,0 +1,250 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+use crate::{message, Error};
+
+/// Creates a rules error under the `rules` field.
+fn rules_error(key: &str) -> Error {
+    Error {
+        field: "rules".to_string(),
+        message: message::get(format!("rules-{}", key))
+    }
+}
+
+/// A source of rules or option lists that can change at runtime, e.g. a config file or an HTTP endpoint.
+///
+/// The crate ships `FileProvider`; remote sources implement this trait over the application's own
+/// HTTP client.
+pub trait RuleProvider: Send + Sync {
+    /// Fetches the current document.
+    ///
+    /// # Returns
+    /// * `Ok(Some(Value))` - The document, if it changed since the last fetch.
+    /// * `Ok(None)` - If the document is unchanged.
+    /// * `Err(Error)` - If the source is unavailable or the document is malformed.
+    fn fetch(&self) -> Result<Option<Value>, Error>;
+
+    /// Records that the document returned by the last `fetch` was applied, so later fetches can
+    /// report it as unchanged. A document that failed to apply is never committed, and is fetched
+    /// again on the next poll.
+    ///
+    /// The default does nothing, for providers that return the document on every fetch.
+    fn commit(&self) {}
+}
+
+/// A `RuleProvider` reading a JSON file, re-read only when its modification time changes from the
+/// last applied one.
+#[derive(Debug)]
+pub struct FileProvider {
+    path: PathBuf,
+    modified: Mutex<Option<SystemTime>>,
+    fetched: Mutex<Option<SystemTime>>
+}
+
+impl FileProvider {
+    /// Creates a provider for the JSON file at `path`.
+    ///
+    /// # Arguments
+    /// * `path` - The file to watch.
+    pub fn new<T>(path: T) -> Self
+    where T: Into<PathBuf>
+    {
+        Self {
+            path: path.into(),
+            modified: Mutex::new(None),
+            fetched: Mutex::new(None)
+        }
+    }
+}
+
+impl RuleProvider for FileProvider {
+    fn fetch(&self) -> Result<Option<Value>, Error> {
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|_| rules_error("unavailable"))?;
+
+        if *self.modified.lock().unwrap_or_else(PoisonError::into_inner) == Some(modified) {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&self.path).map_err(|_| rules_error("unavailable"))?;
+        let value = serde_json::from_str(&contents).map_err(|_| rules_error("invalid"))?;
+
+        *self.fetched.lock().unwrap_or_else(PoisonError::into_inner) = Some(modified);
+
+        Ok(Some(value))
+    }
+
+    fn commit(&self) {
+        let fetched = self.fetched.lock().unwrap_or_else(PoisonError::into_inner).take();
+
+        if fetched.is_some() {
+            *self.modified.lock().unwrap_or_else(PoisonError::into_inner) = fetched;
+        }
+    }
+}
+
+/// Named option lists (countries, plan names, banned words) that can be swapped at runtime.
+///
+/// Clones share the same lists. Lookups hand out an `Arc`, so a reload never changes the list an
+/// in-flight validation is using.
+#[derive(Clone, Debug, Default)]
+pub struct OptionLists {
+    lists: Arc<RwLock<HashMap<String, Arc<[String]>>>>
+}
+
+impl OptionLists {
+    /// Creates an empty set of option lists.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Looks up an option list by name, e.g. for `set_option_list(&countries)`.
+    ///
+    /// # Arguments
+    /// * `name` - The list's name.
+    pub fn get(&self, name: &str) -> Option<Arc<[String]>> {
+        self.lists
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+    }
+
+    /// Replaces every list at once.
+    ///
+    /// # Arguments
+    /// * `lists` - The new lists, keyed by name.
+    pub fn replace(&self, lists: HashMap<String, Vec<String>>) {
+        let lists = lists
+            .into_iter()
+            .map(|(name, options)| (name, Arc::from(options)))
+            .collect::<HashMap<String, Arc<[String]>>>();
+
+        *self.lists.write().unwrap_or_else(PoisonError::into_inner) = lists;
+    }
+
+    /// Replaces every list with the ones in a JSON document such as `{"countries": ["PH", "US"]}`.
+    ///
+    /// The document is fully parsed before anything is swapped, so a malformed one leaves the
+    /// current lists in place.
+    ///
+    /// # Arguments
+    /// * `document` - An object mapping list names to arrays of strings.
+    pub fn reload(&self, document: &Value) -> Result<(), Error> {
+        let lists = document
+            .as_object()
+            .ok_or_else(|| rules_error("invalid"))?
+            .iter()
+            .map(|(name, options)| {
+                let options = options
+                    .as_array()
+                    .and_then(|options| options
+                        .iter()
+                        .map(|option| option.as_str().map(String::from))
+                        .collect::<Option<Vec<String>>>());
+
+                options.map(|options| (name.clone(), options)).ok_or_else(|| rules_error("invalid"))
+            })
+            .collect::<Result<HashMap<String, Vec<String>>, Error>>()?;
+
+        self.replace(lists);
+
+        Ok(())
+    }
+}
+
+/// Fetches from a provider once and applies the document if it changed.
+///
+/// Useful for driving refreshes from an existing scheduler instead of a `Refresher` thread.
+///
+/// # Arguments
+/// * `provider` - The source to fetch from.
+/// * `apply` - Swaps the document in, e.g. `OptionLists::reload` or `RuleRegistry::reload_definitions`.
+///
+/// # Returns
+/// * `Ok(true)` - If a changed document was applied.
+/// * `Ok(false)` - If the document was unchanged.
+/// * `Err(Error)` - If fetching or applying failed; the current rules stay in place, and the document
+///   is fetched and applied again on the next refresh.
+pub fn refresh<P, F>(provider: &P, apply: F) -> Result<bool, Error>
+where P: RuleProvider + ?Sized, F: FnOnce(&Value) -> Result<(), Error>
+{
+    let Some(document) = provider.fetch()? else {
+        return Ok(false);
+    };
+
+    apply(&document)?;
+    provider.commit();
+
+    Ok(true)
+}
+
+/// A background thread polling a `RuleProvider` and applying changes, stopped when dropped.
+#[derive(Debug)]
+pub struct Refresher {
+    stop: Arc<AtomicBool>,
+    last_error: Arc<Mutex<Option<Error>>>,
+    handle: Option<JoinHandle<()>>
+}
+
+impl Refresher {
+    /// Spawns a thread that refreshes immediately and then every `interval`.
+    ///
+    /// # Arguments
+    /// * `provider` - The source to poll.
+    /// * `interval` - The time between polls.
+    /// * `apply` - Swaps each changed document in.
+    pub fn spawn<P, F>(provider: P, interval: Duration, apply: F) -> Self
+    where P: RuleProvider + 'static, F: Fn(&Value) -> Result<(), Error> + Send + 'static
+    {
+        let stop = Arc::new(AtomicBool::new(false));
+        let last_error = Arc::new(Mutex::new(None));
+
+        let handle = {
+            let stop = stop.clone();
+            let last_error = last_error.clone();
+
+            thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    // An unchanged document says nothing about a rejected one, so only a
+                    // successful apply clears the last error
+                    match refresh(&provider, &apply) {
+                        Ok(true) => *last_error.lock().unwrap_or_else(PoisonError::into_inner) = None,
+                        Ok(false) => {},
+                        Err(error) => *last_error.lock().unwrap_or_else(PoisonError::into_inner) = Some(error)
+                    }
+
+                    thread::park_timeout(interval);
+                }
+            })
+        };
+
+        Self {
+            stop,
+            last_error,
+            handle: Some(handle)
+        }
+    }
+
+    /// Returns the error of the most recent failed poll, until a later document is applied successfully.
+    pub fn last_error(&self) -> Option<Error> {
+        self.last_error.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+impl Drop for Refresher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
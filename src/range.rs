@@ -0,0 +1,127 @@
+use nulls::Null;
+
+use crate::{message, Error, Valid, Validator};
+
+/// A range from a `bytes=` range header, with inclusive offsets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `first-last`, e.g. `0-1023`.
+    Span(u64, u64),
+    /// `first-`, from an offset to the end, e.g. `1024-`.
+    From(u64),
+    /// `-length`, the last bytes, e.g. `-500`.
+    Suffix(u64)
+}
+
+/// Parses a range offset: digits only, without a sign.
+fn parse_offset(value: &str) -> Option<u64> {
+    match !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None
+    }
+}
+
+impl Validator {
+    /// Sets the largest number of bytes a single range may span.
+    ///
+    /// # Arguments
+    /// * `max_span` - The maximum span, in bytes.
+    pub fn set_max_span(mut self, max_span: u64) -> Self {
+        self.max_span = Some(max_span);
+        self
+    }
+
+    /// Sets the largest number of ranges a single request may ask for.
+    ///
+    /// # Arguments
+    /// * `max_ranges` - The maximum number of ranges.
+    pub fn set_max_ranges(mut self, max_ranges: usize) -> Self {
+        self.max_ranges = Some(max_ranges);
+        self
+    }
+
+    /// Validates that the string value is a byte range such as `bytes=0-1023` or `bytes=0-99, -500`,
+    /// returning the parsed ranges.
+    ///
+    /// Open-ended ranges such as `1024-` have no known span, so they fail when a maximum span is set.
+    /// An invalid range is reported in the `value` arg.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Vec<ByteRange>>)` - The ranges, in header order, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, malformed, asks for too many ranges, or
+    ///   has a range wider than the maximum span.
+    pub fn validate_byte_range(&self) -> Result<Valid<Vec<ByteRange>>, Error> {
+        let (outcome, ranges) = self.parse_byte_range();
+        self.to_valid(outcome, ranges)
+    }
+
+    /// Parses and checks the ranges, returning the first failure alongside the ranges parsed so far.
+    fn parse_byte_range(&self) -> (Null<String>, Vec<ByteRange>) {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), Vec::new()),
+                false => (Null::Undefined, Vec::new())
+            };
+        }
+
+        let Some(ranges) = value
+            .split_once('=')
+            .filter(|(unit, _)| unit.trim().eq_ignore_ascii_case("bytes"))
+            .map(|(_, ranges)| ranges)
+        else {
+            return (Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("value", value)
+                .build()), Vec::new());
+        };
+
+        let count = ranges.split(',').count();
+
+        if let Some(max) = self.max_ranges
+            && count > max
+        {
+            return (Null::Value(message::new(format!("{}-max-ranges", self.field))
+                .set_args("max", self.bound(max))
+                .set_args("count", count)
+                .build()), Vec::new());
+        }
+
+        let mut parsed = Vec::with_capacity(count);
+
+        for range in ranges.split(',').map(str::trim) {
+            let byte_range = range.split_once('-').and_then(|(first, last)| match (first, last) {
+                ("", last) => parse_offset(last).filter(|length| *length > 0).map(ByteRange::Suffix),
+                (first, "") => parse_offset(first).map(ByteRange::From),
+                (first, last) => match (parse_offset(first), parse_offset(last)) {
+                    (Some(first), Some(last)) if first <= last => Some(ByteRange::Span(first, last)),
+                    _ => None
+                }
+            });
+
+            let Some(byte_range) = byte_range else {
+                return (Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("value", range)
+                    .build()), parsed);
+            };
+
+            let span = match byte_range {
+                ByteRange::Span(first, last) => Some(last - first + 1),
+                ByteRange::From(_) => None,
+                ByteRange::Suffix(length) => Some(length)
+            };
+
+            if let Some(max) = self.max_span
+                && span.is_none_or(|span| span > max)
+            {
+                return (Null::Value(message::new(format!("{}-max-span", self.field))
+                    .set_args("max", self.bound(max))
+                    .build()), parsed);
+            }
+
+            parsed.push(byte_range);
+        }
+
+        (Null::Undefined, parsed)
+    }
+}
@@ -0,0 +1,191 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use crate::{message, Error, RuleChange, ValidatorSet};
+
+static GLOBAL: OnceLock<RuleRegistry> = OnceLock::new();
+
+/// A thread-safe registry of named, optionally versioned `ValidatorSet`s, registered at startup and
+/// looked up in handlers.
+///
+/// Clones share the same sets. Lookups hand out an `Arc`, so a reload swaps the sets atomically
+/// while in-flight validations finish against the version they started with.
+#[derive(Clone, Debug, Default)]
+pub struct RuleRegistry {
+    sets: Arc<RwLock<HashMap<String, Vec<Arc<ValidatorSet>>>>>
+}
+
+impl RuleRegistry {
+    /// Creates an empty registry, for injecting into application state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide registry.
+    pub fn global() -> &'static RuleRegistry {
+        GLOBAL.get_or_init(RuleRegistry::new)
+    }
+
+    /// Registers a rule set under a name and its `version`, replacing a set already registered
+    /// with the same name and version.
+    ///
+    /// # Arguments
+    /// * `name` - The name handlers look the set up by, e.g. `signup`.
+    /// * `set` - The rule set, tagged with `set_version` to keep several versions side by side.
+    ///
+    /// # Returns
+    /// * `Some(Arc<ValidatorSet>)` - The set previously registered under the name and version.
+    /// * `None` - If the name and version were not registered.
+    pub fn register<T>(&self, name: T, set: ValidatorSet) -> Option<Arc<ValidatorSet>>
+    where T: ToString
+    {
+        let mut sets = self.sets.write().unwrap_or_else(PoisonError::into_inner);
+        let versions = sets.entry(name.to_string()).or_default();
+        let set = Arc::new(set);
+
+        match versions.iter().position(|registered| registered.version == set.version) {
+            Some(index) => Some(std::mem::replace(&mut versions[index], set)),
+            None => {
+                versions.push(set);
+                None
+            }
+        }
+    }
+
+    /// Looks up a specific version of a rule set, e.g. `get("signup", "v2")`.
+    ///
+    /// # Arguments
+    /// * `name` - The name the set was registered under.
+    /// * `version` - The set's version tag.
+    pub fn get(&self, name: &str, version: &str) -> Option<Arc<ValidatorSet>> {
+        self.sets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)?
+            .iter()
+            .find(|set| set.version.as_deref() == Some(version))
+            .cloned()
+    }
+
+    /// Looks up the most recently registered version of a rule set.
+    ///
+    /// # Arguments
+    /// * `name` - The name the set was registered under.
+    pub fn latest(&self, name: &str) -> Option<Arc<ValidatorSet>> {
+        self.sets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)?
+            .last()
+            .cloned()
+    }
+
+    /// Returns the version tags registered under a name, in registration order.
+    ///
+    /// # Arguments
+    /// * `name` - The name the sets were registered under.
+    pub fn versions(&self, name: &str) -> Vec<String> {
+        self.sets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter_map(|set| set.version.clone())
+            .collect()
+    }
+
+    /// Describes what changed between two versions of a rule set, for API changelogs and audits.
+    ///
+    /// # Arguments
+    /// * `name` - The name the sets were registered under.
+    /// * `from` - The older version.
+    /// * `to` - The newer version.
+    ///
+    /// # Returns
+    /// * `Some(Vec<RuleChange>)` - The added, removed and changed fields.
+    /// * `None` - If either version is not registered.
+    pub fn diff(&self, name: &str, from: &str, to: &str) -> Option<Vec<RuleChange>> {
+        Some(self.get(name, from)?.diff(&*self.get(name, to)?))
+    }
+
+    /// Removes every version of a rule set by name, returning them if any were registered.
+    ///
+    /// # Arguments
+    /// * `name` - The name the sets were registered under.
+    pub fn remove(&self, name: &str) -> Vec<Arc<ValidatorSet>> {
+        self.sets
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(name)
+            .unwrap_or_default()
+    }
+
+    /// Returns the registered names, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let mut names = self.sets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect::<Vec<String>>();
+
+        names.sort();
+        names
+    }
+
+    /// Replaces every registered set at once.
+    ///
+    /// # Arguments
+    /// * `sets` - The new named rule sets; sets sharing a name are kept as separate versions.
+    pub fn reload<I, T>(&self, sets: I)
+    where I: IntoIterator<Item = (T, ValidatorSet)>, T: ToString
+    {
+        let registry = RuleRegistry::new();
+
+        for (name, set) in sets {
+            registry.register(name, set);
+        }
+
+        let sets = std::mem::take(&mut *registry.sets.write().unwrap_or_else(PoisonError::into_inner));
+
+        *self.sets.write().unwrap_or_else(PoisonError::into_inner) = sets;
+    }
+
+    /// Replaces every registered set with the ones described by a config file's JSON.
+    ///
+    /// Keys are set names, optionally suffixed with a version as in `signup@v2`. The definitions are
+    /// all parsed before anything is swapped, so an invalid config leaves the current sets in place.
+    ///
+    /// # Arguments
+    /// * `definitions` - An object mapping set names to `ValidatorSet::from_definition` objects.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If every set was parsed and swapped in.
+    /// * `Err(Error)` - The first invalid set or field.
+    pub fn reload_definitions(&self, definitions: &Value) -> Result<(), Error> {
+        let Some(definitions) = definitions.as_object() else {
+            return Err(Error {
+                field: "rules".to_string(),
+                message: message::get("rules-invalid")
+            });
+        };
+
+        let sets = definitions
+            .iter()
+            .map(|(key, definition)| {
+                let set = ValidatorSet::from_definition(definition)?;
+
+                Ok(match key.split_once('@') {
+                    Some((name, version)) => (name.to_string(), set.set_version(version)),
+                    None => (key.clone(), set)
+                })
+            })
+            .collect::<Result<Vec<(String, ValidatorSet)>, Error>>()?;
+
+        self.reload(sets);
+
+        Ok(())
+    }
+}
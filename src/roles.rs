@@ -0,0 +1,48 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Validates that the string value is a role the context's actor may assign.
+    ///
+    /// Known roles come from the option list when one is set, and otherwise from every role in the
+    /// context's role matrix. Unknown roles are reported as `{field}-invalid`, and known roles the
+    /// actor may not assign as `{field}-forbidden`, so the two can be told apart.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, the role is unknown, or the actor may not assign it.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_role_assignment(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let is_known = match &self.option_list_string {
+            Some(list) => self.is_listed(list),
+            None => self.context.assignable_roles.values().any(|roles| self.is_listed(roles))
+        };
+
+        if !is_known {
+            return Null::Value(message::new(format!("{}-invalid", self.field))
+                .set_args("role", self.list_formatter().quote(&self.string_value))
+                .build());
+        }
+
+        let actor = self.context.actor.clone().unwrap_or_default();
+        let is_assignable = self.context.assignable_roles
+            .get(&actor)
+            .is_some_and(|roles| self.is_listed(roles));
+
+        if !is_assignable {
+            return Null::Value(message::new(format!("{}-forbidden", self.field))
+                .set_args("role", self.list_formatter().quote(&self.string_value))
+                .set_args("actor", actor)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,108 @@
+use nulls::Null;
+use std::collections::BTreeSet;
+
+use crate::{message, Error, Valid, Validator};
+
+impl Validator {
+    /// Sets the scopes a scope string may request.
+    ///
+    /// # Arguments
+    /// * `allowed_scopes` - A slice of allowed scopes, e.g. `["read", "write", "admin"]`.
+    pub fn set_allowed_scopes<T>(mut self, allowed_scopes: &[T]) -> Self
+    where T: ToString
+    {
+        self.allowed_scopes = allowed_scopes.iter().map(|scope| scope.to_string()).collect::<Vec<String>>();
+        self
+    }
+
+    /// Declares that requesting a scope also grants other scopes, e.g. `admin` implies `read`.
+    ///
+    /// Can be called once per implying scope; implications are followed transitively.
+    ///
+    /// # Arguments
+    /// * `scope` - The implying scope.
+    /// * `implied` - A slice of scopes granted along with it.
+    pub fn set_scope_implication<T, U>(mut self, scope: T, implied: &[U]) -> Self
+    where T: ToString, U: ToString
+    {
+        self.scope_implications.push((
+            scope.to_string(),
+            implied.iter().map(|value| value.to_string()).collect::<Vec<String>>()
+        ));
+
+        self
+    }
+
+    /// Validates an OAuth-style, space-delimited scope string such as `"read write"`, returning the
+    /// normalized scope set.
+    ///
+    /// Each scope must be in the allowed scopes, and is normalized to its allowed spelling unless the
+    /// validator is case sensitive. Implied scopes are added and duplicates removed.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<BTreeSet<String>>)` - The requested and implied scopes, if the validation passes successfully.
+    /// * `Err(Error)` - If the field is required but empty, or a scope is malformed or not allowed.
+    pub fn validate_scopes(&self) -> Result<Valid<BTreeSet<String>>, Error> {
+        let (outcome, scopes) = self.parse_scopes();
+        self.to_valid(outcome, scopes)
+    }
+
+    /// Parses and checks the scope string, returning the first failure alongside the scopes parsed so far.
+    fn parse_scopes(&self) -> (Null<String>, BTreeSet<String>) {
+        let mut scopes = BTreeSet::new();
+
+        if self.string_value.trim().is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), scopes),
+                false => (Null::Undefined, scopes)
+            };
+        }
+
+        for scope in self.string_value.split_whitespace() {
+            // RFC 6749 scope tokens: printable ASCII except space, `"` and `\`
+            if !scope.bytes().all(|byte| matches!(byte, 0x21 | 0x23..=0x5b | 0x5d..=0x7e)) {
+                return (Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("scope", self.list_formatter().quote(scope))
+                    .build()), scopes);
+            }
+
+            let Some(allowed) = self.allowed_scope(scope) else {
+                return (Null::Value(message::new(format!("{}-unknown-scope", self.field))
+                    .set_args("scope", self.list_formatter().quote(scope))
+                    .set_args("options", self.format_options(&self.allowed_scopes))
+                    .build()), scopes);
+            };
+
+            scopes.insert(allowed.clone());
+        }
+
+        // Follow implications until no new scope is granted
+        let mut pending = scopes.iter().cloned().collect::<Vec<String>>();
+
+        while let Some(scope) = pending.pop() {
+            for (implying, implied) in &self.scope_implications {
+                if self.allowed_scope(implying).is_none_or(|allowed| *allowed != scope) {
+                    continue;
+                }
+
+                for implied in implied {
+                    let implied = self.allowed_scope(implied).cloned().unwrap_or_else(|| implied.clone());
+
+                    if scopes.insert(implied.clone()) {
+                        pending.push(implied);
+                    }
+                }
+            }
+        }
+
+        (Null::Undefined, scopes)
+    }
+
+    /// Finds the allowed scope matching a requested scope, honoring case sensitivity.
+    fn allowed_scope(&self, scope: &str) -> Option<&String> {
+        self.allowed_scopes.iter().find(|allowed| match self.is_case_sensitive {
+            true => *allowed == scope,
+            false => allowed.eq_ignore_ascii_case(scope)
+        })
+    }
+}
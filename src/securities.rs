@@ -0,0 +1,137 @@
+use nulls::Null;
+
+use crate::{checksum, message, Validator};
+
+const MAX_TICKER: usize = 6;
+
+impl Validator {
+    /// Sets the exchange suffixes tickers may carry, such as `L` for `VOD.L` or `T` for `7203.T`.
+    ///
+    /// # Arguments
+    /// * `ticker_suffixes` - A slice of allowed suffixes, without the leading dot.
+    pub fn set_ticker_suffixes<T>(mut self, ticker_suffixes: &[T]) -> Self
+    where T: ToString
+    {
+        self.ticker_suffixes = ticker_suffixes.iter().map(|suffix| suffix.to_string()).collect::<Vec<String>>();
+        self
+    }
+
+    /// Configures whether tickers must carry one of the exchange suffixes.
+    ///
+    /// # Arguments
+    /// * `is_suffix_required` - A boolean indicating if bare tickers are rejected.
+    pub fn set_as_suffix_required(mut self, is_suffix_required: bool) -> Self {
+        self.is_suffix_required = is_suffix_required;
+        self
+    }
+
+    /// Validates that the string value is a stock ticker such as `AAPL`, `BRK.B` or `VOD.L`.
+    ///
+    /// The symbol is up to `max` (default 6) letters and digits, optionally followed by a one-letter
+    /// share class after `.` or `-`. A trailing `.` segment matching one of the exchange suffixes is
+    /// treated as the exchange. Lowercase is accepted unless the validator is case sensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or missing a required or allowed suffix.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ticker(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if self.is_case_sensitive && value.bytes().any(|byte| byte.is_ascii_lowercase()) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let value = value.to_ascii_uppercase();
+        let suffix = value.rsplit_once('.').and_then(|(symbol, suffix)| {
+            self.ticker_suffixes
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(suffix))
+                .then_some(symbol)
+        });
+
+        let symbol = match suffix {
+            Some(symbol) => symbol,
+            None if self.is_suffix_required => {
+                return Null::Value(message::new(format!("{}-suffix", self.field))
+                    .set_args("options", self.format_options(&self.ticker_suffixes))
+                    .build());
+            },
+            None => value.as_str()
+        };
+
+        let (root, class) = match symbol.rsplit_once(['.', '-']) {
+            Some((root, class)) => (root, Some(class)),
+            None => (symbol, None)
+        };
+
+        let max = self.max.unwrap_or(MAX_TICKER);
+        let is_root = !root.is_empty()
+            && root.len() <= max
+            && root.bytes().all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit());
+        let is_class = class.is_none_or(|class| class.len() == 1 && class.bytes().all(|byte| byte.is_ascii_uppercase()));
+
+        if !is_root || !is_class {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value is an ISIN such as `US0378331005`.
+    ///
+    /// An ISIN is a two-letter country code, nine letters or digits and a check digit. Letters count as
+    /// `A` = 10 through `Z` = 35 and the resulting digits must pass the Luhn check.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or has a wrong check digit.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_isin(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_ascii_uppercase()
+        };
+
+        let bytes = value.as_bytes();
+        let is_format = bytes.len() == 12
+            && bytes[..2].iter().all(u8::is_ascii_uppercase)
+            && bytes[2..11].iter().all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+            && bytes[11].is_ascii_digit();
+
+        if !is_format {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let digits = bytes
+            .iter()
+            .flat_map(|byte| match byte {
+                b'0'..=b'9' => vec![byte - b'0'],
+                _ => {
+                    let value = byte - b'A' + 10;
+                    vec![value / 10, value % 10]
+                }
+            })
+            .collect::<Vec<u8>>();
+
+        if !checksum::luhn(&digits) {
+            return Null::Value(message::get(format!("{}-checksum", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,210 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+#[cfg(feature = "hmac")]
+use hmac::{Hmac, Mac};
+
+/// The hash function used for HMAC signatures.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HmacAlgorithm {
+    #[default]
+    Sha256,
+    Sha384,
+    Sha512
+}
+
+impl HmacAlgorithm {
+    /// Returns the algorithm's lowercase name, as used in `sha256=…` signature prefixes.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HmacAlgorithm::Sha256 => "sha256",
+            HmacAlgorithm::Sha384 => "sha384",
+            HmacAlgorithm::Sha512 => "sha512"
+        }
+    }
+}
+
+/// Compares two byte strings in time that depends only on their lengths, not their contents.
+///
+/// # Arguments
+/// * `a` - The first byte string.
+/// * `b` - The second byte string.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let len = a.len().max(b.len());
+    let mut diff = u8::from(a.len() != b.len());
+
+    for i in 0..len {
+        diff |= a.get(i).copied().unwrap_or_default() ^ b.get(i).copied().unwrap_or_default();
+    }
+
+    std::hint::black_box(diff) == 0
+}
+
+impl Validator {
+    /// Sets the secret the string value is compared against by `validate_equals_constant_time`.
+    ///
+    /// # Arguments
+    /// * `secret` - The expected secret, e.g. an API token or webhook signature.
+    pub fn set_expected_secret<T>(mut self, secret: T) -> Self
+    where T: ToString
+    {
+        self.expected_secret = secret.to_string();
+        self
+    }
+
+    /// Sets the key used to verify HMAC signatures.
+    ///
+    /// # Arguments
+    /// * `key` - The shared secret key.
+    pub fn set_hmac_key<T>(mut self, key: T) -> Self
+    where T: AsRef<[u8]>
+    {
+        self.hmac_key = key.as_ref().to_vec();
+        self
+    }
+
+    /// Sets the hash function used to verify HMAC signatures.
+    ///
+    /// # Arguments
+    /// * `hmac_algorithm` - The HMAC hash function. Defaults to SHA-256.
+    pub fn set_hmac_algorithm(mut self, hmac_algorithm: HmacAlgorithm) -> Self {
+        self.hmac_algorithm = hmac_algorithm;
+        self
+    }
+
+    /// Sets the payload the signature is computed over, e.g. a raw webhook request body.
+    ///
+    /// # Arguments
+    /// * `payload` - The signed bytes.
+    pub fn set_signed_payload<T>(mut self, payload: T) -> Self
+    where T: AsRef<[u8]>
+    {
+        self.signed_payload = payload.as_ref().to_vec();
+        self
+    }
+
+    /// Validates that the string value is a valid HMAC signature of the signed payload.
+    ///
+    /// The signature may be hex or base64 encoded, optionally prefixed with the algorithm name
+    /// (`sha256=…`), and is compared in constant time.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is empty, the key is missing, or the signature does not match.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "hmac")]
+    pub fn validate_hmac_signature(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return Null::Value(message::get(format!("{}-empty", self.field)));
+        }
+
+        let prefix = format!("{}=", self.hmac_algorithm.as_str());
+        let signature = self.string_value.trim();
+        let signature = signature.strip_prefix(prefix.as_str()).unwrap_or(signature);
+
+        let is_valid = match decode_signature(signature) {
+            Some(signature) if !self.hmac_key.is_empty() => {
+                sign(self.hmac_algorithm, &self.hmac_key, &self.signed_payload)
+                    .is_some_and(|expected| constant_time_eq(&expected, &signature))
+            },
+            _ => false
+        };
+
+        if !is_valid {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value equals the expected secret, without timing side channels.
+    ///
+    /// The comparison time depends only on the lengths of both strings.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is empty, or if the value does not match the expected secret.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_equals_constant_time(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return Null::Value(message::get(format!("{}-empty", self.field)));
+        }
+
+        if self.expected_secret.is_empty()
+            || !constant_time_eq(self.string_value.as_bytes(), self.expected_secret.as_bytes())
+        {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
+
+/// Computes the HMAC of a payload with the given algorithm and key.
+///
+/// # Arguments
+/// * `algorithm` - The HMAC hash function.
+/// * `key` - The shared secret key.
+/// * `payload` - The bytes to sign.
+#[cfg(feature = "hmac")]
+pub(crate) fn sign(algorithm: HmacAlgorithm, key: &[u8], payload: &[u8]) -> Option<Vec<u8>> {
+    match algorithm {
+        HmacAlgorithm::Sha256 => {
+            let mut mac = Hmac::<sha2::Sha256>::new_from_slice(key).ok()?;
+            mac.update(payload);
+            Some(mac.finalize().into_bytes().to_vec())
+        },
+        HmacAlgorithm::Sha384 => {
+            let mut mac = Hmac::<sha2::Sha384>::new_from_slice(key).ok()?;
+            mac.update(payload);
+            Some(mac.finalize().into_bytes().to_vec())
+        },
+        HmacAlgorithm::Sha512 => {
+            let mut mac = Hmac::<sha2::Sha512>::new_from_slice(key).ok()?;
+            mac.update(payload);
+            Some(mac.finalize().into_bytes().to_vec())
+        }
+    }
+}
+
+/// Computes the HMAC of a payload bound to a purpose, signing `purpose ‖ 0x00 ‖ payload`.
+///
+/// Tokens the crate issues itself are signed this way, so a token issued for one purpose is never a
+/// valid signature for another, nor for a webhook payload checked by `validate_hmac_signature`.
+///
+/// # Arguments
+/// * `algorithm` - The HMAC hash function.
+/// * `key` - The shared secret key.
+/// * `purpose` - What the token is for, e.g. `cursor`.
+/// * `payload` - The bytes to sign.
+#[cfg(feature = "hmac")]
+pub(crate) fn sign_for(algorithm: HmacAlgorithm, key: &[u8], purpose: &str, payload: &[u8]) -> Option<Vec<u8>> {
+    let mut tagged = Vec::with_capacity(purpose.len() + 1 + payload.len());
+    tagged.extend_from_slice(purpose.as_bytes());
+    tagged.push(0);
+    tagged.extend_from_slice(payload);
+
+    sign(algorithm, key, &tagged)
+}
+
+/// Decodes a hex or base64 (standard or URL-safe, padded or not) encoded signature.
+///
+/// # Arguments
+/// * `signature` - The encoded signature.
+#[cfg(feature = "hmac")]
+pub(crate) fn decode_signature(signature: &str) -> Option<Vec<u8>> {
+    use base64::Engine;
+    use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+
+    if signature.len().is_multiple_of(2) && signature.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return (0..signature.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&signature[i..i + 2], 16).ok())
+            .collect();
+    }
+
+    STANDARD.decode(signature)
+        .or_else(|_| STANDARD_NO_PAD.decode(signature))
+        .or_else(|_| URL_SAFE.decode(signature))
+        .or_else(|_| URL_SAFE_NO_PAD.decode(signature))
+        .ok()
+}
@@ -0,0 +1,103 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// Checks for a numeric identifier without leading zeros, e.g. `0` or `12`.
+fn is_numeric(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier.bytes().all(|byte| byte.is_ascii_digit())
+        && (identifier == "0" || !identifier.starts_with('0'))
+}
+
+/// Checks for dot-separated, non-empty identifiers of ASCII letters, digits and hyphens.
+///
+/// Pre-release identifiers that are all digits must also be free of leading zeros.
+fn is_identifiers(value: &str, is_prerelease: bool) -> bool {
+    value.split('.').all(|identifier| {
+        let is_alphanumeric = !identifier.is_empty()
+            && identifier.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+
+        match is_prerelease && identifier.bytes().all(|byte| byte.is_ascii_digit()) {
+            true => is_numeric(identifier),
+            false => is_alphanumeric
+        }
+    })
+}
+
+/// Splits a SemVer 2.0 version such as `1.2.3-rc.1+build.5` into its pre-release and build metadata.
+fn parse_semver(value: &str) -> Option<(Option<&str>, Option<&str>)> {
+    let (version, build) = match value.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (value, None)
+    };
+
+    let (core, prerelease) = match version.split_once('-') {
+        Some((core, prerelease)) => (core, Some(prerelease)),
+        None => (version, None)
+    };
+
+    let parts = core.split('.').collect::<Vec<&str>>();
+
+    match parts.len() == 3
+        && parts.iter().all(|part| is_numeric(part))
+        && prerelease.is_none_or(|prerelease| is_identifiers(prerelease, true))
+        && build.is_none_or(|build| is_identifiers(build, false))
+    {
+        true => Some((prerelease, build)),
+        false => None
+    }
+}
+
+impl Validator {
+    /// Rejects versions with a pre-release segment, such as `1.0.0-beta.1`.
+    ///
+    /// # Arguments
+    /// * `is_prerelease_forbidden` - Whether pre-release versions are rejected.
+    pub fn set_as_prerelease_forbidden(mut self, is_prerelease_forbidden: bool) -> Self {
+        self.is_prerelease_forbidden = is_prerelease_forbidden;
+        self
+    }
+
+    /// Rejects versions with build metadata, such as `1.0.0+20240101`.
+    ///
+    /// # Arguments
+    /// * `is_build_metadata_forbidden` - Whether build metadata is rejected.
+    pub fn set_as_build_metadata_forbidden(mut self, is_build_metadata_forbidden: bool) -> Self {
+        self.is_build_metadata_forbidden = is_build_metadata_forbidden;
+        self
+    }
+
+    /// Validates that the string value is a SemVer 2.0 version, such as `1.4.0` or `2.0.0-rc.1+build.7`.
+    ///
+    /// Major, minor and patch numbers cannot have leading zeros, and a `v` prefix is not part of the
+    /// version. Forbidden segments are reported with `{field}-prerelease` and `{field}-build-metadata`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a SemVer version, or has a forbidden
+    ///   pre-release or build metadata segment.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_semver(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some((prerelease, build)) = parse_semver(value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        if self.is_prerelease_forbidden && prerelease.is_some() {
+            return Null::Value(message::get(format!("{}-prerelease", self.field)));
+        }
+
+        if self.is_build_metadata_forbidden && build.is_some() {
+            return Null::Value(message::get(format!("{}-build-metadata", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
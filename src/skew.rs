@@ -0,0 +1,94 @@
+use nulls::Null;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{message, temporal, Validator};
+
+const MAX_SKEW: Duration = Duration::from_secs(300);
+
+/// Returns the current time as a Unix timestamp in seconds.
+pub(crate) type Clock = Arc<dyn Fn() -> i64 + Send + Sync>;
+
+/// Parses Unix seconds, an RFC 3339 timestamp or a `YYYY-MM-DDTHH:MM:SS` UTC timestamp into Unix seconds.
+pub(crate) fn parse_timestamp(value: &str) -> Option<i64> {
+    match value.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => value.parse::<i64>().ok(),
+        false => temporal::parse_instant(value).map(|instant| instant.and_utc().timestamp())
+    }
+}
+
+impl Validator {
+    /// Replaces the system clock used by time-based checks, e.g. with a fixed time in tests.
+    ///
+    /// # Arguments
+    /// * `clock` - A function returning the current Unix timestamp in seconds.
+    pub fn set_clock<F>(mut self, clock: F) -> Self
+    where F: Fn() -> i64 + Send + Sync + 'static
+    {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Sets how far a timestamp may drift from now, in either direction.
+    ///
+    /// # Arguments
+    /// * `max_skew` - The maximum difference. Defaults to 5 minutes.
+    pub fn set_max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = Some(max_skew);
+        self
+    }
+
+    /// Returns the current Unix timestamp in seconds, from the configured clock or the system clock.
+    pub(crate) fn now(&self) -> i64 {
+        match &self.clock {
+            Some(clock) => clock(),
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|now| now.as_secs() as i64)
+                .unwrap_or_default()
+        }
+    }
+
+    /// Returns how far a timestamp is from now and the maximum skew allowed, both in seconds.
+    ///
+    /// # Arguments
+    /// * `timestamp` - The Unix timestamp in seconds; later than now gives a positive skew.
+    pub(crate) fn skew(&self, timestamp: i64) -> (i64, i64) {
+        (timestamp.saturating_sub(self.now()), self.max_skew.unwrap_or(MAX_SKEW).as_secs() as i64)
+    }
+
+    /// Validates that the string value is a timestamp within the maximum skew of now, as used to
+    /// reject replayed request signatures.
+    ///
+    /// Timestamps are Unix seconds, RFC 3339 timestamps or `YYYY-MM-DDTHH:MM:SS` UTC timestamps. The
+    /// difference from now is reported in the `skew` arg, in seconds.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or too far from now.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_timestamp_skew(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let Some(timestamp) = parse_timestamp(value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let (skew, max) = self.skew(timestamp);
+
+        if skew.saturating_abs() > max {
+            return Null::Value(message::new(format!("{}-skew", self.field))
+                .set_args("skew", skew.to_string())
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
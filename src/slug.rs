@@ -0,0 +1,39 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Validates that the string value is a URL slug such as `hello-world-2`, within the
+    /// minimum/maximum length constraints.
+    ///
+    /// Slugs are lowercase letters, digits and hyphens. Hyphens only separate words, so a slug cannot
+    /// start or end with one or contain two in a row; those are reported with `{field}-hyphen`.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, violates the length constraints, has a
+    ///   character other than a lowercase letter, digit or hyphen, or misplaces a hyphen.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_slug(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = self.validate_string_length();
+        if value.is_some() {
+            return value;
+        }
+
+        if !self.string_value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if self.string_value.split('-').any(str::is_empty) {
+            return Null::Value(message::get(format!("{}-hyphen", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,119 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use nulls::Null;
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+use crate::security::{constant_time_eq, sign_for};
+use crate::{message, Context, Error, Valid, Validator};
+
+const STEP_PURPOSE: &str = "step";
+const STEP_TTL: Duration = Duration::from_secs(3600);
+
+impl Validator {
+    /// Sets how long step tokens issued by `issue_step_token` stay valid.
+    ///
+    /// # Arguments
+    /// * `step_ttl` - The lifetime of a step token. Defaults to 1 hour.
+    pub fn set_step_ttl(mut self, step_ttl: Duration) -> Self {
+        self.step_ttl = Some(step_ttl);
+        self
+    }
+
+    /// Issues a step token carrying the answers of earlier wizard steps, signed with the HMAC key.
+    ///
+    /// The token is `<envelope>.<signature>`, both base64url encoded, where the envelope holds the
+    /// answers and their expiry, and is meant to be round-tripped through the client between steps.
+    /// The signature is bound to step tokens, so a step token is never accepted as a cursor or webhook
+    /// signature made with the same key.
+    ///
+    /// # Arguments
+    /// * `answers` - The validated answers so far, as a JSON object.
+    ///
+    /// # Returns
+    /// * `Some(String)` - The token.
+    /// * `None` - If the HMAC key is missing.
+    pub fn issue_step_token(&self, answers: &Value) -> Option<String> {
+        if self.hmac_key.is_empty() {
+            return None;
+        }
+
+        let expires_at = self.now().saturating_add(self.step_ttl.unwrap_or(STEP_TTL).as_secs() as i64);
+
+        let mut envelope = Map::new();
+        envelope.insert("data".to_string(), answers.clone());
+        envelope.insert("expires_at".to_string(), Value::from(expires_at));
+
+        let envelope = URL_SAFE_NO_PAD.encode(serde_json::to_string(&Value::Object(envelope)).ok()?);
+        let signature = sign_for(self.hmac_algorithm, &self.hmac_key, STEP_PURPOSE, envelope.as_bytes())?;
+
+        Some(format!("{}.{}", envelope, URL_SAFE_NO_PAD.encode(signature)))
+    }
+
+    /// Validates that the string value is a step token issued by `issue_step_token` with the same
+    /// HMAC key and algorithm, returning the earlier answers as a `Context`.
+    ///
+    /// The context can be handed to later steps' validators through `set_context`, or its values read
+    /// directly, so cross-step rules run server-side without storing wizard state.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Context>)` - The earlier answers, or none for an empty optional value, if the token
+    ///   is authentic.
+    /// * `Err(Error)` - If the field is required but empty, the key is missing, the token is malformed or tampered
+    ///   with, or it has expired.
+    pub fn validate_step_token(&self) -> Result<Valid<Context>, Error> {
+        let (outcome, context) = self.verify_step_token();
+        self.to_valid(outcome, context)
+    }
+
+    /// Verifies the step token's signature and expiry, and decodes its answers.
+    fn verify_step_token(&self) -> (Null<String>, Context) {
+        let invalid = || (Null::Value(message::get(format!("{}-invalid", self.field))), Context::new());
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), Context::new()),
+                false => (Null::Undefined, Context::new())
+            };
+        }
+
+        let Some((envelope, signature)) = value.split_once('.') else {
+            return invalid();
+        };
+
+        let is_authentic = match URL_SAFE_NO_PAD.decode(signature) {
+            Ok(signature) if !self.hmac_key.is_empty() => {
+                sign_for(self.hmac_algorithm, &self.hmac_key, STEP_PURPOSE, envelope.as_bytes())
+                    .is_some_and(|expected| constant_time_eq(&expected, &signature))
+            },
+            _ => false
+        };
+
+        if !is_authentic {
+            return invalid();
+        }
+
+        let envelope = URL_SAFE_NO_PAD
+            .decode(envelope)
+            .ok()
+            .and_then(|envelope| serde_json::from_slice::<Value>(&envelope).ok());
+
+        let Some(Value::Object(envelope)) = envelope else {
+            return invalid();
+        };
+
+        let (Some(answers), Some(expires_at)) = (
+            envelope.get("data").filter(|answers| answers.is_object()),
+            envelope.get("expires_at").and_then(Value::as_i64)
+        ) else {
+            return invalid();
+        };
+
+        if self.now() > expires_at {
+            return (Null::Value(message::get(format!("{}-expired", self.field))), Context::new());
+        }
+
+        (Null::Undefined, Context::from_payload(answers))
+    }
+}
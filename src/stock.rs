@@ -0,0 +1,67 @@
+use nulls::Null;
+use serde_json::Value;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Sets the live amount available, such as the units in stock.
+    ///
+    /// # Arguments
+    /// * `available` - The available amount.
+    pub fn set_available(mut self, available: i64) -> Self {
+        self.available = Some(available);
+        self
+    }
+
+    /// Reads the live amount available from a named context value when no amount is set directly.
+    ///
+    /// # Arguments
+    /// * `key` - The context value's name, e.g. `stock`.
+    pub fn set_available_from<T>(mut self, key: T) -> Self
+    where T: ToString
+    {
+        self.available_key = Some(key.to_string());
+        self
+    }
+
+    /// Validates that the `i64` value does not exceed the live amount available.
+    ///
+    /// Unlike the static `max`, the limit comes from `set_available` or the context, and is passed as
+    /// the `available` arg so messages can read "only 3 left". Nothing available is reported as
+    /// `{field}-unavailable` instead. The check is skipped when no amount is known.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, nothing is available, or the value exceeds the amount available.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_not_exceeding(&self) -> Null<String> {
+        let Some(value) = self.i64_value else {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        };
+
+        let available = self.available.or_else(|| {
+            self.available_key
+                .as_deref()
+                .and_then(|key| self.context.get(key))
+                .and_then(Value::as_i64)
+        });
+
+        let Some(available) = available else {
+            return Null::Undefined;
+        };
+
+        if value > 0 && available <= 0 {
+            return Null::Value(message::get(format!("{}-unavailable", self.field)));
+        }
+
+        if value > available {
+            return Null::Value(message::new(format!("{}-exceeds-available", self.field))
+                .set_args("available", available)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
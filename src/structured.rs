@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+
+use nulls::Null;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Sets the map value for the validator, defaulting to an empty map if null or undefined.
+    ///
+    /// # Arguments
+    /// * `map` - A nullable JSON object.
+    pub fn set_map_value(mut self, map: &Null<Map<String, Value>>) -> Self {
+        self.map_value = map.clone().take().unwrap_or_default();
+        self
+    }
+
+    /// Sets the map value for the validator from a string-to-string map.
+    ///
+    /// # Arguments
+    /// * `map` - A nullable `HashMap<String, String>`.
+    pub fn set_string_map_value(mut self, map: &Null<HashMap<String, String>>) -> Self {
+        self.map_value = map
+            .clone()
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, Value::String(value)))
+            .collect::<Map<String, Value>>();
+
+        self
+    }
+
+    /// Sets the maximum number of entries allowed in the map.
+    ///
+    /// # Arguments
+    /// * `max_entries` - The maximum number of entries.
+    pub fn set_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Sets the maximum length of each map key.
+    ///
+    /// # Arguments
+    /// * `max_key_len` - The maximum key length.
+    pub fn set_max_key_len(mut self, max_key_len: usize) -> Self {
+        self.max_key_len = Some(max_key_len);
+        self
+    }
+
+    /// Sets the maximum length of each map value. Non-string values are measured by their JSON encoding.
+    ///
+    /// # Arguments
+    /// * `max_value_len` - The maximum value length.
+    pub fn set_max_value_len(mut self, max_value_len: usize) -> Self {
+        self.max_value_len = Some(max_value_len);
+        self
+    }
+
+    /// Sets the regular expression every map key must match.
+    ///
+    /// # Arguments
+    /// * `key_pattern` - The key pattern, e.g. `^[a-z_]+$`.
+    #[cfg(feature = "regex")]
+    pub fn set_key_pattern<T>(mut self, key_pattern: T) -> Self
+    where T: ToString
+    {
+        self.key_pattern = Some(key_pattern.to_string());
+        self
+    }
+
+    /// Sets the list of keys that must not appear in the map.
+    ///
+    /// # Arguments
+    /// * `forbidden_keys` - A slice of items convertible to strings.
+    pub fn set_forbidden_keys<T>(mut self, forbidden_keys: &[T]) -> Self
+    where T: ToString
+    {
+        self.forbidden_keys = forbidden_keys
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>();
+
+        self
+    }
+
+    /// Sets the JSON value for the validator, defaulting to `Value::Null` if null or undefined.
+    ///
+    /// # Arguments
+    /// * `json` - A nullable JSON value.
+    pub fn set_json_value(mut self, json: &Null<Value>) -> Self {
+        self.json_value = json.clone().take().unwrap_or_default();
+        self
+    }
+
+    /// Sets the maximum nesting depth of arrays and objects.
+    ///
+    /// # Arguments
+    /// * `max_depth` - The maximum depth, where a flat object or array has a depth of `1`.
+    pub fn set_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the maximum number of nodes (containers and scalars) in the JSON value.
+    ///
+    /// # Arguments
+    /// * `max_total_nodes` - The maximum node count.
+    pub fn set_max_total_nodes(mut self, max_total_nodes: usize) -> Self {
+        self.max_total_nodes = Some(max_total_nodes);
+        self
+    }
+
+    /// Sets the maximum length of any string, including object keys, in the JSON value.
+    ///
+    /// # Arguments
+    /// * `max_string_len` - The maximum string length.
+    pub fn set_max_string_len(mut self, max_string_len: usize) -> Self {
+        self.max_string_len = Some(max_string_len);
+        self
+    }
+
+    /// Validates that the JSON value stays within the configured depth, node count and string length limits.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but null, or if any limit is exceeded.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_json_value(&self) -> Null<String> {
+        if self.is_required && self.json_value.is_null() {
+            return Null::Value(message::get(format!("{}-empty", self.field)));
+        }
+
+        self.validate_json_limits(&self.json_value)
+    }
+
+    /// Walks a JSON value without recursion and reports the first exceeded limit.
+    fn validate_json_limits(&self, value: &Value) -> Null<String> {
+        let mut stack = vec![(value, 0)];
+        let mut nodes = 0;
+
+        while let Some((value, depth)) = stack.pop() {
+            nodes += 1;
+
+            if let Some(max) = self.max_total_nodes
+                && nodes > max
+            {
+                return Null::Value(message::new(format!("{}-max-nodes", self.field))
+                    .set_args("max", self.bound(max))
+                    .build());
+            }
+
+            let (is_container, max_string) = match value {
+                Value::String(string) => (false, string.len()),
+                Value::Array(array) => {
+                    stack.extend(array.iter().map(|item| (item, depth + 1)));
+                    (true, 0)
+                },
+                Value::Object(object) => {
+                    stack.extend(object.values().map(|item| (item, depth + 1)));
+                    (true, object.keys().map(|key| key.len()).max().unwrap_or_default())
+                },
+                _ => (false, 0)
+            };
+
+            if let Some(max) = self.max_depth
+                && is_container
+                && depth + 1 > max
+            {
+                return Null::Value(message::new(format!("{}-max-depth", self.field))
+                    .set_args("max", self.bound(max))
+                    .build());
+            }
+
+            if let Some(max) = self.max_string_len
+                && max_string > max
+            {
+                return Null::Value(message::new(format!("{}-max-string", self.field))
+                    .set_args("max", self.bound(max))
+                    .build());
+            }
+        }
+
+        Null::Undefined
+    }
+
+    /// Configures whether XML documents may contain a DTD. External entities are never resolved.
+    ///
+    /// # Arguments
+    /// * `is_dtd_allowed` - A boolean indicating if a `<!DOCTYPE>` declaration is accepted.
+    #[cfg(feature = "xml")]
+    pub fn set_as_dtd_allowed(mut self, is_dtd_allowed: bool) -> Self {
+        self.is_dtd_allowed = is_dtd_allowed;
+        self
+    }
+
+    /// Validates that the string value is a well-formed XML document.
+    ///
+    /// The byte length is limited by `max` and the node count by `max_total_nodes`. Parse errors
+    /// carry the `line` and `column` of the failure as i18n args.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, exceeds a limit, contains a forbidden DTD, or is malformed.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "xml")]
+    pub fn validate_xml(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(max) = self.max
+            && self.string_value.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: self.is_dtd_allowed,
+            nodes_limit: self.max_total_nodes
+                .map(|max| u32::try_from(max).unwrap_or(u32::MAX))
+                .unwrap_or(u32::MAX)
+        };
+
+        match roxmltree::Document::parse_with_options(&self.string_value, options) {
+            Ok(_) => Null::Undefined,
+            Err(roxmltree::Error::DtdDetected) => {
+                Null::Value(message::get(format!("{}-dtd", self.field)))
+            },
+            Err(roxmltree::Error::NodesLimitReached) => {
+                Null::Value(message::new(format!("{}-max-nodes", self.field))
+                    .set_args("max", self.bound(self.max_total_nodes.unwrap_or_default()))
+                    .build())
+            },
+            Err(error) => {
+                let position = error.pos();
+
+                Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("line", position.row.to_string())
+                    .set_args("column", position.col.to_string())
+                    .build())
+            }
+        }
+    }
+
+    /// Validates that the string value is a well-formed YAML snippet within the configured limits.
+    ///
+    /// The byte length is limited by `max`, and the parsed document by `max_depth`, `max_total_nodes`
+    /// and `max_string_len`. Parse errors carry the `line` and `column` of the failure as i18n args.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, exceeds a limit, or fails to parse.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "yaml")]
+    pub fn validate_yaml(&self) -> Null<String> {
+        self.validate_snippet(|snippet| {
+            serde_yaml::from_str::<Value>(snippet).map_err(|error| {
+                error.location()
+                    .map(|location| (location.line(), location.column()))
+                    .unwrap_or((1, 1))
+            })
+        })
+    }
+
+    /// Validates that the string value is a well-formed TOML snippet within the configured limits.
+    ///
+    /// The byte length is limited by `max`, and the parsed document by `max_depth`, `max_total_nodes`
+    /// and `max_string_len`. Parse errors carry the `line` and `column` of the failure as i18n args.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, exceeds a limit, or fails to parse.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "toml")]
+    pub fn validate_toml(&self) -> Null<String> {
+        self.validate_snippet(|snippet| {
+            toml::from_str::<Value>(snippet).map_err(|error| {
+                error.span()
+                    .map(|span| line_column(snippet, span.start))
+                    .unwrap_or((1, 1))
+            })
+        })
+    }
+
+    /// Parses a configuration snippet with the given parser and applies the size and JSON limits.
+    #[cfg(any(feature = "yaml", feature = "toml"))]
+    fn validate_snippet<F>(&self, parse: F) -> Null<String>
+    where F: FnOnce(&str) -> Result<Value, (usize, usize)>
+    {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(max) = self.max
+            && self.string_value.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        match parse(&self.string_value) {
+            Ok(value) => self.validate_json_limits(&value),
+            Err((line, column)) => {
+                Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("line", line.to_string())
+                    .set_args("column", column.to_string())
+                    .build())
+            }
+        }
+    }
+
+    /// Validates that the map value meets the configured entry, key and value constraints.
+    ///
+    /// Errors concerning the whole map (emptiness, entry count) are keyed by the field name,
+    /// while all other errors are keyed by the offending map key.
+    ///
+    /// # Returns
+    /// * `Null::Value` - A map of errors if the map is required but empty, has too many entries, or contains invalid entries.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_map(&self) -> Null<Value> {
+        let mut errors = Map::new();
+
+        if self.is_required && self.map_value.is_empty() {
+            errors.insert(
+                self.field.clone(),
+                message::get(format!("{}-empty", self.field)).into()
+            );
+
+            return Null::Value(Value::Object(errors));
+        }
+
+        if let Some(max) = self.max_entries
+            && self.map_value.len() > max
+        {
+            errors.insert(
+                self.field.clone(),
+                message::new(format!("{}-max-entries", self.field))
+                    .set_args("max", self.bound(max))
+                    .build()
+                    .into()
+            );
+
+            return Null::Value(Value::Object(errors));
+        }
+
+        #[cfg(feature = "regex")]
+        let pattern = match &self.key_pattern {
+            Some(pattern) => match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(_) => {
+                    errors.insert(
+                        self.field.clone(),
+                        message::get(format!("{}-invalid", self.field)).into()
+                    );
+
+                    return Null::Value(Value::Object(errors));
+                }
+            },
+            None => None
+        };
+
+        for (key, value) in &self.map_value {
+            let error = match () {
+                _ if self.forbidden_keys.contains(key) => {
+                    message::new(format!("{}-key-forbidden", self.field))
+                        .set_args("key", key.as_str())
+                        .build()
+                },
+                _ if self.max_key_len.is_some_and(|max| key.len() > max) => {
+                    message::new(format!("{}-key-max", self.field))
+                        .set_args("key", key.as_str())
+                        .set_args("max", self.bound(self.max_key_len.unwrap_or_default()))
+                        .build()
+                },
+                #[cfg(feature = "regex")]
+                _ if pattern.as_ref().is_some_and(|re| !re.is_match(key)) => {
+                    message::new(format!("{}-key-invalid", self.field))
+                        .set_args("key", key.as_str())
+                        .build()
+                },
+                _ if self.max_value_len.is_some_and(|max| value_len(value) > max) => {
+                    message::new(format!("{}-value-max", self.field))
+                        .set_args("key", key.as_str())
+                        .set_args("max", self.bound(self.max_value_len.unwrap_or_default()))
+                        .build()
+                },
+                _ => continue
+            };
+
+            errors.insert(key.clone(), error.into());
+        }
+
+        if !errors.is_empty() {
+            return Null::Value(Value::Object(errors));
+        }
+
+        Null::Undefined
+    }
+}
+
+/// Measures a JSON value's length: the string length for strings, the encoded length otherwise.
+fn value_len(value: &Value) -> usize {
+    match value {
+        Value::String(string) => string.len(),
+        value => serde_json::to_string(value).unwrap_or_default().len()
+    }
+}
+
+/// Converts a byte offset into a 1-based line and column pair.
+#[cfg(feature = "toml")]
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = text.get(..offset).unwrap_or(text);
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+    (line, column)
+}
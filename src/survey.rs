@@ -0,0 +1,318 @@
+use nulls::Null;
+use serde_json::{Map, Value};
+
+use crate::form::{is_absent, sanitize};
+use crate::{fingerprint, message, Error, Message, Report, Validator};
+
+const SCALE_MIN: usize = 1;
+const SCALE_MAX: usize = 5;
+
+/// The kind of answer a `Question` expects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QuestionKind {
+    /// One of the question's options, as a string.
+    #[default]
+    SingleChoice,
+    /// Several of the question's options, as an array of strings.
+    MultiChoice,
+    /// Free text, as a string.
+    FreeText,
+    /// An integer on a scale, as a number or numeric string.
+    Scale
+}
+
+impl QuestionKind {
+    /// Looks up a question kind by its definition name: `single_choice`, `multi_choice`, `free_text` or `scale`.
+    ///
+    /// # Arguments
+    /// * `name` - The kind's name, as used in question definitions.
+    pub fn from_name(name: &str) -> Option<QuestionKind> {
+        match name {
+            "single_choice" => Some(QuestionKind::SingleChoice),
+            "multi_choice" => Some(QuestionKind::MultiChoice),
+            "free_text" => Some(QuestionKind::FreeText),
+            "scale" => Some(QuestionKind::Scale),
+            _ => None
+        }
+    }
+}
+
+/// A survey question and the constraints on its answer.
+///
+/// `min` and `max` bound the number of selections for multi-choice questions, the length for
+/// free-text questions, and the range for scale questions (1 to 5 unless set).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Question {
+    pub id: String,
+    pub kind: QuestionKind,
+    pub is_required: bool,
+    pub options: Vec<String>,
+    pub min: Option<usize>,
+    pub max: Option<usize>
+}
+
+impl Question {
+    /// Creates an optional question without constraints.
+    ///
+    /// # Arguments
+    /// * `id` - The question ID, used as the field name in errors and i18n keys.
+    /// * `kind` - The kind of answer expected.
+    pub fn new<T>(id: T, kind: QuestionKind) -> Self
+    where T: ToString
+    {
+        Self { id: id.to_string(), kind, ..Self::default() }
+    }
+
+    /// Marks the question as requiring an answer.
+    pub fn required(mut self) -> Self {
+        self.is_required = true;
+        self
+    }
+
+    /// Sets the options of a choice question.
+    ///
+    /// # Arguments
+    /// * `options` - A slice of option values.
+    pub fn options<T>(mut self, options: &[T]) -> Self
+    where T: ToString
+    {
+        self.options = options.iter().map(|option| option.to_string()).collect::<Vec<String>>();
+        self
+    }
+
+    /// Sets the minimum number of selections, length, or scale value.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum allowed.
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum number of selections, length, or scale value.
+    ///
+    /// # Arguments
+    /// * `max` - The maximum allowed.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Builds a question from a JSON definition, e.g.
+    /// `{"id": "q1", "type": "multi_choice", "options": ["a", "b", "c"], "max": 2}`.
+    ///
+    /// Supported keys are `id`, `type` (defaulting to `single_choice`), `required`, `options`, `min` and `max`.
+    ///
+    /// # Arguments
+    /// * `definition` - The question definition object.
+    ///
+    /// # Returns
+    /// * `Some(Question)` - The question described by the definition.
+    /// * `None` - If the definition is not an object, lacks an `id`, names an unknown type, or has a mistyped key.
+    pub fn from_definition(definition: &Value) -> Option<Question> {
+        let definition = definition.as_object()?;
+        let count = |key: &str| match definition.get(key) {
+            None => Some(None),
+            Some(value) => value.as_u64().and_then(|value| usize::try_from(value).ok()).map(Some)
+        };
+
+        let kind = match definition.get("type") {
+            None => QuestionKind::SingleChoice,
+            Some(kind) => QuestionKind::from_name(kind.as_str()?)?
+        };
+
+        let options = match definition.get("options") {
+            None => Vec::new(),
+            Some(options) => options
+                .as_array()?
+                .iter()
+                .map(|option| option.as_str().map(String::from))
+                .collect::<Option<Vec<String>>>()?
+        };
+
+        Some(Question {
+            id: definition.get("id")?.as_str()?.to_string(),
+            kind,
+            is_required: match definition.get("required") {
+                None => false,
+                Some(value) => value.as_bool()?
+            },
+            options,
+            min: count("min")?,
+            max: count("max")?
+        })
+    }
+
+    /// Validates an answer against this question.
+    ///
+    /// Missing, `null`, empty string and empty array answers only fail when the question is required.
+    ///
+    /// # Arguments
+    /// * `answer` - The answer, if present.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the question is required but unanswered, the answer has the wrong type, or fails the constraints.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate(&self, answer: Option<&Value>) -> Null<String> {
+        message::render(self.check(answer))
+    }
+
+    /// Validates an answer like `validate`, returning the unrendered message behind an error.
+    pub(crate) fn check(&self, answer: Option<&Value>) -> Null<Message> {
+        let answer = answer.map(sanitize);
+        let is_unanswered = is_absent(answer.as_ref())
+            || answer.as_ref().and_then(Value::as_array).is_some_and(Vec::is_empty);
+
+        let Some(answer) = answer.filter(|_| !is_unanswered) else {
+            return match self.is_required {
+                true => Null::Value(message::key(format!("{}-empty", self.id))),
+                false => Null::Undefined
+            };
+        };
+
+        let validator = Validator::new(&self.id)
+            .set_as_required(true)
+            .set_option_list_string(&self.options);
+        let invalid = || Null::Value(message::key(format!("{}-invalid", self.id)));
+
+        match self.kind {
+            QuestionKind::SingleChoice => match answer.as_str() {
+                Some(choice) => validator.set_string_value(&Null::Value(choice.to_string())).check_list_options(),
+                None => invalid()
+            },
+            QuestionKind::MultiChoice => {
+                let Some(choices) = answer.as_array().and_then(|choices| {
+                    choices.iter().map(Value::as_str).collect::<Option<Vec<&str>>>()
+                }) else {
+                    return invalid();
+                };
+
+                if let Some(min) = self.min
+                    && choices.len() < min
+                {
+                    return Null::Value(message::new(format!("{}-min-selections", self.id))
+                        .set_args("min", min)
+                        .set_args("count", choices.len())
+                        .into_message());
+                }
+
+                if let Some(max) = self.max
+                    && choices.len() > max
+                {
+                    return Null::Value(message::new(format!("{}-max-selections", self.id))
+                        .set_args("max", max)
+                        .set_args("count", choices.len())
+                        .into_message());
+                }
+
+                for (index, choice) in choices.iter().enumerate() {
+                    if choices[..index].contains(choice) {
+                        return Null::Value(message::new(format!("{}-duplicate", self.id))
+                            .set_args("option", validator.list_formatter().quote(choice))
+                            .into_message());
+                    }
+
+                    let outcome = validator.clone()
+                        .set_string_value(&Null::Value(choice.to_string()))
+                        .check_list_options();
+
+                    if outcome.is_some() {
+                        return outcome;
+                    }
+                }
+
+                Null::Undefined
+            },
+            QuestionKind::FreeText => match answer.as_str() {
+                Some(text) => Validator { min: self.min, max: self.max, ..validator }
+                    .set_string_value(&Null::Value(text.to_string()))
+                    .check_string(),
+                None => invalid()
+            },
+            QuestionKind::Scale => {
+                let value = answer.as_i64().or_else(|| answer.as_str().and_then(|s| s.parse().ok()));
+
+                match value {
+                    Some(value) => Validator {
+                        min: Some(self.min.unwrap_or(SCALE_MIN)),
+                        max: Some(self.max.unwrap_or(SCALE_MAX)),
+                        ..validator
+                    }
+                        .set_i64_value(&Null::Value(value))
+                        .check_i64(),
+                    None => invalid()
+                }
+            }
+        }
+    }
+}
+
+/// A questionnaire: an ordered list of questions validated together against a JSON answer object.
+#[derive(Clone, Debug, Default)]
+pub struct Survey {
+    pub questions: Vec<Question>
+}
+
+/// Creates an empty `Survey`.
+pub fn survey() -> Survey {
+    Survey::default()
+}
+
+impl Survey {
+    /// Adds a question.
+    ///
+    /// # Arguments
+    /// * `question` - The question.
+    pub fn question(mut self, question: Question) -> Self {
+        self.questions.push(question);
+        self
+    }
+
+    /// Builds a survey from a JSON array of question definitions.
+    ///
+    /// # Arguments
+    /// * `definition` - An array such as `[{"id": "q1", "type": "scale", "max": 10}]`.
+    ///
+    /// # Returns
+    /// * `Ok(Survey)` - The survey described by the definition.
+    /// * `Err(Error)` - The first invalid question, with its `{id}-invalid` message, or `survey-invalid`
+    ///   if the definition is not an array or a question lacks an ID.
+    pub fn from_definition(definition: &Value) -> Result<Survey, Error> {
+        let invalid = |id: &str| Error {
+            field: id.to_string(),
+            message: message::get(format!("{}-invalid", id))
+        };
+
+        let Some(questions) = definition.as_array() else {
+            return Err(invalid("survey"));
+        };
+
+        questions.iter().try_fold(survey(), |survey, question| match Question::from_definition(question) {
+            Some(question) => Ok(survey.question(question)),
+            None => Err(invalid(question.get("id").and_then(Value::as_str).unwrap_or("survey")))
+        })
+    }
+
+    /// Validates an answer object keyed by question ID, collecting errors keyed by question ID.
+    ///
+    /// # Arguments
+    /// * `answers` - The JSON object to validate.
+    pub fn validate(&self, answers: &Value) -> Report {
+        let mut report = Report::default();
+
+        for question in &self.questions {
+            if let Null::Value(message) = question.check(answers.get(question.id.as_str())) {
+                report.errors.insert(question.id.clone(), message.render().into());
+                report.messages.insert(question.id.clone(), message);
+            }
+        }
+
+        let values = self.questions
+            .iter()
+            .map(|question| (question.id.clone(), answers.get(question.id.as_str()).cloned().unwrap_or_default()))
+            .collect::<Map<String, Value>>();
+
+        report.fingerprint = fingerprint::fingerprint(&Value::Object(values));
+        report
+    }
+}
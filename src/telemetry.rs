@@ -0,0 +1,190 @@
+use nulls::Null;
+use serde_json::{Map, Value};
+
+use crate::{message, Validator};
+
+/// The type of reading a sensor reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SensorKind {
+    Bool,
+    Int,
+    Float
+}
+
+impl SensorKind {
+    /// Returns the name reported in the `kind` arg of a type mismatch.
+    fn name(self) -> &'static str {
+        match self {
+            SensorKind::Bool => "bool",
+            SensorKind::Int => "int",
+            SensorKind::Float => "float"
+        }
+    }
+}
+
+/// An expected sensor reading in a telemetry payload, e.g. `Sensor::float("temp").range(-40.0, 85.0)`.
+///
+/// Sensors are `const`, so a device schema can live in a `static` and be validated without allocating.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sensor {
+    pub key: &'static str,
+    pub kind: SensorKind,
+    pub is_required: bool,
+    pub min: Option<f64>,
+    pub max: Option<f64>
+}
+
+impl Sensor {
+    /// Creates an optional sensor of the given kind.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the reading in the payload.
+    /// * `kind` - The type of the reading.
+    pub const fn new(key: &'static str, kind: SensorKind) -> Self {
+        Sensor { key, kind, is_required: false, min: None, max: None }
+    }
+
+    /// Creates a sensor reporting `true`/`false`.
+    pub const fn bool(key: &'static str) -> Self {
+        Sensor::new(key, SensorKind::Bool)
+    }
+
+    /// Creates a sensor reporting whole numbers.
+    pub const fn int(key: &'static str) -> Self {
+        Sensor::new(key, SensorKind::Int)
+    }
+
+    /// Creates a sensor reporting any number.
+    pub const fn float(key: &'static str) -> Self {
+        Sensor::new(key, SensorKind::Float)
+    }
+
+    /// Marks the reading as required.
+    pub const fn required(mut self) -> Self {
+        self.is_required = true;
+        self
+    }
+
+    /// Sets the inclusive range of a numeric reading.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum reading.
+    /// * `max` - The maximum reading.
+    pub const fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+}
+
+impl Validator {
+    /// Sets the sensors a telemetry payload is checked against.
+    ///
+    /// # Arguments
+    /// * `sensors` - A static slice of sensors, in the order a positional payload lists them.
+    pub fn set_sensors(mut self, sensors: &'static [Sensor]) -> Self {
+        self.sensors = sensors;
+        self
+    }
+
+    /// Validates a telemetry payload against the sensors.
+    ///
+    /// The payload is the JSON value, either an object keyed by sensor or, for compact encodings, an
+    /// array listing the readings in sensor order, with `null` for a skipped reading. The map value is
+    /// used when no JSON value is set. Keys not in the schema are ignored.
+    ///
+    /// Nothing is allocated unless a reading fails. Errors concerning the whole payload are keyed by
+    /// the field name, while reading errors are keyed by the sensor and carry it in the `key` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - A map of errors if the payload is required but empty, is not an object or array,
+    ///   has more positional readings than sensors, or has missing, mistyped or out-of-range readings.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_telemetry(&self) -> Null<Value> {
+        let mut errors = Map::new();
+
+        let (object, array) = match &self.json_value {
+            Value::Object(object) => (Some(object), None),
+            Value::Array(array) => (None, Some(array)),
+            Value::Null => (Some(&self.map_value), None),
+            _ => (None, None)
+        };
+
+        let is_empty = object.is_some_and(Map::is_empty) || array.is_some_and(Vec::is_empty);
+
+        let error = match () {
+            _ if is_empty && self.is_required => Some(message::get(format!("{}-empty", self.field))),
+            _ if is_empty => return Null::Undefined,
+            _ if object.is_none() && array.is_none_or(|array| array.len() > self.sensors.len()) => {
+                Some(message::get(format!("{}-invalid", self.field)))
+            },
+            _ => None
+        };
+
+        if let Some(error) = error {
+            errors.insert(self.field.clone(), error.into());
+            return Null::Value(Value::Object(errors));
+        }
+
+        for (index, sensor) in self.sensors.iter().enumerate() {
+            let reading = match (object, array) {
+                (Some(object), _) => object.get(sensor.key),
+                (_, Some(array)) => array.get(index),
+                _ => None
+            };
+
+            if let Some(error) = self.validate_reading(sensor, reading.unwrap_or(&Value::Null)) {
+                errors.insert(sensor.key.to_string(), error.into());
+            }
+        }
+
+        if !errors.is_empty() {
+            return Null::Value(Value::Object(errors));
+        }
+
+        Null::Undefined
+    }
+
+    /// Checks a single reading against its sensor, returning the error message if it fails.
+    fn validate_reading(&self, sensor: &Sensor, reading: &Value) -> Option<String> {
+        let is_typed = match sensor.kind {
+            _ if reading.is_null() => {
+                return sensor.is_required.then(|| message::new(format!("{}-sensor-missing", self.field))
+                    .set_args("key", sensor.key)
+                    .build());
+            },
+            SensorKind::Bool => reading.is_boolean(),
+            SensorKind::Int => reading.is_i64() || reading.is_u64(),
+            SensorKind::Float => reading.is_number()
+        };
+
+        if !is_typed {
+            return Some(message::new(format!("{}-sensor-type", self.field))
+                .set_args("key", sensor.key)
+                .set_args("kind", sensor.kind.name())
+                .build());
+        }
+
+        let number = reading.as_f64()?;
+
+        if let Some(min) = sensor.min
+            && number < min
+        {
+            return Some(message::new(format!("{}-sensor-min", self.field))
+                .set_args("key", sensor.key)
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if let Some(max) = sensor.max
+            && number > max
+        {
+            return Some(message::new(format!("{}-sensor-max", self.field))
+                .set_args("key", sensor.key)
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        None
+    }
+}
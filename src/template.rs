@@ -0,0 +1,501 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// The placeholder syntax used by a user-editable template.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TemplateSyntax {
+    /// Single-brace placeholders, e.g. `Hello {name}`.
+    #[default]
+    Braces,
+    /// Handlebars/Mustache tags, e.g. `Hello {{name}}` or `{{#if vip}}…{{/if}}`.
+    Handlebars,
+    /// Liquid output and logic tags, e.g. `Hello {{ name | upcase }}` or `{% for item in items %}`.
+    Liquid
+}
+
+/// The kind of a parsed template tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TagKind {
+    Output,
+    Raw,
+    Open,
+    Close,
+    Else,
+    Partial,
+    Comment
+}
+
+/// A parsed template tag with its character position in the template.
+#[derive(Clone, Debug)]
+struct Tag {
+    kind: TagKind,
+    helper: String,
+    arguments: Vec<String>,
+    locals: Vec<String>,
+    position: usize
+}
+
+impl Tag {
+    /// Creates a tag without arguments or locals.
+    fn new(kind: TagKind, helper: String, position: usize) -> Self {
+        Tag { kind, helper, arguments: vec![], locals: vec![], position }
+    }
+
+    /// Returns the data names referenced by the tag, excluding helpers, literals and scope keywords.
+    fn placeholders(&self) -> Vec<String> {
+        let words = match self.kind {
+            TagKind::Output | TagKind::Raw | TagKind::Open if self.arguments.is_empty() => {
+                vec![self.helper.clone()]
+            },
+            TagKind::Output | TagKind::Raw | TagKind::Open | TagKind::Else => self.arguments.clone(),
+            _ => vec![]
+        };
+
+        words.iter()
+            .map(|word| word.rsplit('=').next().unwrap_or_default())
+            .filter(|word| !word.is_empty()
+                && !word.starts_with(['"', '\'', '@', '.', '('])
+                && !word.starts_with(|c: char| c.is_ascii_digit()))
+            .map(|word| word.split(['.', '/', '[']).next().unwrap_or_default().to_string())
+            .filter(|word| !matches!(word.as_str(), "this" | "true" | "false" | "null" | "nil"))
+            .collect()
+    }
+
+    /// Checks whether the tag opens a loop block.
+    fn is_loop(&self) -> bool {
+        self.kind == TagKind::Open && matches!(self.helper.as_str(), "each" | "for" | "tablerow")
+    }
+}
+
+impl Validator {
+    /// Sets the placeholder syntax used by `validate_template` and `lint_template`.
+    ///
+    /// # Arguments
+    /// * `template_syntax` - The template syntax.
+    pub fn set_template_syntax(mut self, template_syntax: TemplateSyntax) -> Self {
+        self.template_syntax = template_syntax;
+        self
+    }
+
+    /// Sets the placeholders a template is allowed to reference.
+    ///
+    /// # Arguments
+    /// * `placeholders` - A slice of items convertible to strings.
+    pub fn set_placeholders<T>(mut self, placeholders: &[T]) -> Self
+    where T: ToString
+    {
+        self.placeholders = Some(placeholders
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>());
+
+        self
+    }
+
+    /// Sets how deeply loops (`each`, `for`, `tablerow`) may be nested when linting templates.
+    ///
+    /// # Arguments
+    /// * `max_loop_depth` - The maximum loop nesting depth; `0` forbids loops entirely.
+    pub fn set_max_loop_depth(mut self, max_loop_depth: usize) -> Self {
+        self.max_loop_depth = Some(max_loop_depth);
+        self
+    }
+
+    /// Validates that the string value is a well-formed template referencing only allowed placeholders.
+    ///
+    /// Syntax errors carry the character `position` of the offending tag, and unknown placeholders are
+    /// reported through `{field}-placeholder` with the `placeholder` arg. Loop variables and Liquid
+    /// `assign`/`capture` names are allowed once defined.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too long, malformed, references an unknown placeholder, or times out.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_template(&self) -> Null<String> {
+        if self.timeout.is_some() {
+            return self.validate_with_timeout(Validator::validate_template);
+        }
+
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if let Some(max) = self.max
+            && self.string_value.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        let tags = match parse(&self.string_value, self.template_syntax) {
+            Ok(tags) => tags,
+            Err(position) => {
+                return Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("position", position.to_string())
+                    .build());
+            }
+        };
+
+        if let Some(allowed) = &self.placeholders {
+            let mut locals = vec!["forloop".to_string(), "tablerowloop".to_string()];
+
+            for tag in &tags {
+                if let Some(placeholder) = tag.placeholders()
+                    .into_iter()
+                    .find(|placeholder| !allowed.contains(placeholder) && !locals.contains(placeholder))
+                {
+                    return Null::Value(message::new(format!("{}-placeholder", self.field))
+                        .set_args("placeholder", placeholder)
+                        .set_args("position", tag.position.to_string())
+                        .build());
+                }
+
+                locals.extend(tag.locals.iter().cloned());
+            }
+        }
+
+        Null::Undefined
+    }
+
+    /// Lints the string value for dangerous template constructs.
+    ///
+    /// Reports every partial/include (`{field}-include`), raw HTML output such as `{{{…}}}`, `{{&…}}`
+    /// or Liquid's `raw` filter (`{field}-raw`), and loop nested deeper than `max_loop_depth`
+    /// (`{field}-loop-depth`), each with the character `position` of the offending tag.
+    ///
+    /// # Returns
+    /// * `Null::Value` - A list of violations, or a single error if the template is malformed.
+    /// * `Null::Undefined` - If no violations are found.
+    pub fn lint_template(&self) -> Null<Vec<String>> {
+        let tags = match parse(&self.string_value, self.template_syntax) {
+            Ok(tags) => tags,
+            Err(position) => {
+                return Null::Value(vec![message::new(format!("{}-invalid", self.field))
+                    .set_args("position", position.to_string())
+                    .build()]);
+            }
+        };
+
+        let mut errors = Vec::new();
+        let mut blocks: Vec<bool> = Vec::new();
+
+        for tag in &tags {
+            if self.is_over_error_budget(&errors) {
+                break;
+            }
+
+            match tag.kind {
+                TagKind::Partial => {
+                    errors.push(message::new(format!("{}-include", self.field))
+                        .set_args("name", tag.helper.as_str())
+                        .set_args("position", tag.position.to_string())
+                        .build());
+                },
+                TagKind::Raw => {
+                    errors.push(message::new(format!("{}-raw", self.field))
+                        .set_args("position", tag.position.to_string())
+                        .build());
+                },
+                TagKind::Open => {
+                    let depth = blocks.iter().filter(|is_loop| **is_loop).count() + 1;
+
+                    if let Some(max) = self.max_loop_depth
+                        && tag.is_loop()
+                        && depth > max
+                    {
+                        errors.push(message::new(format!("{}-loop-depth", self.field))
+                            .set_args("max", self.bound(max))
+                            .set_args("position", tag.position.to_string())
+                            .build());
+                    }
+
+                    blocks.push(tag.is_loop());
+                },
+                TagKind::Close => {
+                    blocks.pop();
+                },
+                _ => {}
+            }
+        }
+
+        if errors.is_empty() {
+            return Null::Undefined;
+        }
+
+        Null::Value(self.cap_errors(errors))
+    }
+}
+
+/// Parses a template into its tags, returning the character position of the first syntax error.
+fn parse(template: &str, syntax: TemplateSyntax) -> Result<Vec<Tag>, usize> {
+    let chars = template.chars().collect::<Vec<char>>();
+
+    match syntax {
+        TemplateSyntax::Braces => parse_braces(&chars),
+        TemplateSyntax::Handlebars => parse_handlebars(&chars),
+        TemplateSyntax::Liquid => parse_liquid(&chars)
+    }
+}
+
+/// Parses single-brace `{name}` placeholders.
+fn parse_braces(chars: &[char]) -> Result<Vec<Tag>, usize> {
+    let mut tags = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '{' => {
+                let end = (i + 1..chars.len())
+                    .find(|j| matches!(chars[*j], '{' | '}'))
+                    .filter(|j| chars[*j] == '}')
+                    .ok_or(i)?;
+
+                let name = chars[i + 1..end].iter().collect::<String>().trim().to_string();
+
+                if !is_identifier(&name) {
+                    return Err(i);
+                }
+
+                tags.push(Tag::new(TagKind::Output, name, i));
+                i = end;
+            },
+            '}' => return Err(i),
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    Ok(tags)
+}
+
+/// Parses Handlebars/Mustache `{{…}}` and `{{{…}}}` tags and checks block nesting.
+fn parse_handlebars(chars: &[char]) -> Result<Vec<Tag>, usize> {
+    let mut tags = Vec::new();
+    let mut blocks: Vec<(String, usize)> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '}' && chars.get(i + 1) == Some(&'}') {
+            return Err(i);
+        }
+
+        if chars[i] != '{' || chars.get(i + 1) != Some(&'{') {
+            i += 1;
+            continue;
+        }
+
+        let is_triple = chars.get(i + 2) == Some(&'{');
+        let delimiter = if is_triple { 3 } else { 2 };
+        let end = find_closing(chars, i + delimiter, &['}'; 3][..delimiter]).ok_or(i)?;
+        let inner = trim_tag(&chars[i + delimiter..end], '~');
+
+        let (kind, body) = match inner.chars().next() {
+            _ if is_triple => (TagKind::Raw, inner.as_str()),
+            Some('!') => (TagKind::Comment, ""),
+            Some('#') => (TagKind::Open, &inner[1..]),
+            Some('^') if inner.len() == 1 => (TagKind::Else, ""),
+            Some('^') => (TagKind::Open, &inner[1..]),
+            Some('/') => (TagKind::Close, &inner[1..]),
+            Some('>') => (TagKind::Partial, &inner[1..]),
+            Some('&') => (TagKind::Raw, &inner[1..]),
+            _ if inner == "else" => (TagKind::Else, ""),
+            _ => (TagKind::Output, inner.as_str())
+        };
+
+        let mut words = body.split_whitespace().map(|word| word.to_string());
+        let mut tag = Tag::new(kind, words.next().unwrap_or_default(), i);
+
+        // Block params, e.g. `{{#each items as |item index|}}`
+        let words = words.collect::<Vec<String>>();
+        let (arguments, params) = match words.iter().position(|word| word == "as") {
+            Some(index) => (words[..index].to_vec(), words[index + 1..].to_vec()),
+            None => (words, vec![])
+        };
+
+        tag.arguments = arguments;
+        tag.locals = params.iter()
+            .map(|param| param.trim_matches('|').to_string())
+            .filter(|param| !param.is_empty())
+            .collect();
+
+        if tag.helper.is_empty() && !matches!(kind, TagKind::Comment | TagKind::Else) {
+            return Err(i);
+        }
+
+        match kind {
+            TagKind::Open => blocks.push((tag.helper.clone(), i)),
+            TagKind::Close => match blocks.pop() {
+                Some((open, _)) if open == tag.helper => {},
+                _ => return Err(i)
+            },
+            TagKind::Else if blocks.is_empty() => return Err(i),
+            _ => {}
+        }
+
+        tags.push(tag);
+        i = end + delimiter;
+    }
+
+    match blocks.pop() {
+        Some((_, position)) => Err(position),
+        None => Ok(tags)
+    }
+}
+
+/// Parses Liquid `{{ … }}` output and `{% … %}` logic tags and checks block nesting.
+fn parse_liquid(chars: &[char]) -> Result<Vec<Tag>, usize> {
+    const BLOCKS: [&str; 7] = ["for", "tablerow", "if", "unless", "case", "capture", "comment"];
+    const OPERATORS: [&str; 12] = ["==", "!=", "<>", "<", ">", "<=", ">=", "and", "or", "contains", "empty", "blank"];
+
+    let mut tags = Vec::new();
+    let mut blocks: Vec<(String, usize)> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let is_output = chars[i] == '{' && chars.get(i + 1) == Some(&'{');
+        let is_logic = chars[i] == '{' && chars.get(i + 1) == Some(&'%');
+
+        if !is_output && !is_logic {
+            i += 1;
+            continue;
+        }
+
+        let closing = if is_output { ['}', '}'] } else { ['%', '}'] };
+        let end = find_closing(chars, i + 2, &closing).ok_or(i)?;
+        let inner = trim_tag(&chars[i + 2..end], '-');
+
+        if is_output {
+            let mut parts = inner.split('|');
+            let expression = parts.next().unwrap_or_default().trim();
+            let is_raw = parts.any(|filter| filter.trim().starts_with("raw"));
+            let kind = if is_raw { TagKind::Raw } else { TagKind::Output };
+
+            if expression.is_empty() {
+                return Err(i);
+            }
+
+            tags.push(Tag::new(kind, expression.to_string(), i));
+            i = end + 2;
+            continue;
+        }
+
+        let words = inner.split_whitespace().map(|word| word.to_string()).collect::<Vec<String>>();
+        let name = words.first().cloned().ok_or(i)?;
+        let rest = words[1..].to_vec();
+        let mut tag = Tag::new(TagKind::Comment, name.clone(), i);
+
+        match name.as_str() {
+            "for" | "tablerow" => {
+                tag.kind = TagKind::Open;
+                tag.locals = rest.first().cloned().into_iter().collect();
+                tag.arguments = rest.iter()
+                    .skip_while(|word| *word != "in")
+                    .nth(1)
+                    .cloned()
+                    .into_iter()
+                    .collect();
+            },
+            "if" | "unless" | "case" | "elsif" | "when" => {
+                tag.kind = if matches!(name.as_str(), "elsif" | "when") { TagKind::Else } else { TagKind::Open };
+                tag.arguments = rest.into_iter()
+                    .filter(|word| !OPERATORS.contains(&word.as_str()))
+                    .collect();
+            },
+            "capture" => {
+                tag.kind = TagKind::Open;
+                tag.locals = rest.into_iter().take(1).collect();
+            },
+            "assign" => {
+                tag.kind = TagKind::Output;
+                tag.locals = rest.first().cloned().into_iter().collect();
+                tag.arguments = rest.iter()
+                    .skip_while(|word| *word != "=")
+                    .nth(1)
+                    .map(|word| word.split('|').next().unwrap_or_default().to_string())
+                    .into_iter()
+                    .collect();
+            },
+            "echo" => {
+                tag.kind = TagKind::Output;
+                tag.arguments = rest.into_iter().take(1).collect();
+            },
+            "include" | "render" => {
+                tag.kind = TagKind::Partial;
+                tag.helper = rest.first()
+                    .map(|word| word.trim_matches(['\'', '"', ',']).to_string())
+                    .unwrap_or_default();
+            },
+            "raw" | "comment" => {
+                // Everything up to the matching end tag is literal text
+                let end_tag = format!("end{}", name);
+                let mut j = end + 2;
+
+                loop {
+                    let start = (j..chars.len().saturating_sub(1))
+                        .find(|k| chars[*k] == '{' && chars[*k + 1] == '%')
+                        .ok_or(i)?;
+                    let close = find_closing(chars, start + 2, &['%', '}']).ok_or(start)?;
+
+                    j = close + 2;
+
+                    if trim_tag(&chars[start + 2..close], '-') == end_tag {
+                        break;
+                    }
+                }
+
+                tags.push(tag);
+                i = j;
+                continue;
+            },
+            "else" => tag.kind = TagKind::Else,
+            "break" | "continue" | "cycle" | "increment" | "decrement" => {},
+            _ if name.starts_with("end") && BLOCKS.contains(&&name[3..]) => {
+                tag.kind = TagKind::Close;
+                tag.helper = name[3..].to_string();
+            },
+            _ => return Err(i)
+        }
+
+        match tag.kind {
+            TagKind::Open => blocks.push((tag.helper.clone(), i)),
+            TagKind::Close => match blocks.pop() {
+                Some((open, _)) if open == tag.helper => {},
+                _ => return Err(i)
+            },
+            TagKind::Else if blocks.is_empty() => return Err(i),
+            _ => {}
+        }
+
+        tags.push(tag);
+        i = end + 2;
+    }
+
+    match blocks.pop() {
+        Some((_, position)) => Err(position),
+        None => Ok(tags)
+    }
+}
+
+/// Finds the position of the closing delimiter at or after `from`.
+fn find_closing(chars: &[char], from: usize, delimiter: &[char]) -> Option<usize> {
+    (from..(chars.len() + 1).saturating_sub(delimiter.len()))
+        .find(|j| chars[*j..*j + delimiter.len()] == *delimiter)
+}
+
+/// Collects a tag's inner characters, trimming whitespace and whitespace-control markers.
+fn trim_tag(chars: &[char], control: char) -> String {
+    chars.iter()
+        .collect::<String>()
+        .trim_matches(|c: char| c == control || c.is_whitespace())
+        .to_string()
+}
+
+/// Checks that a placeholder name only uses letters, digits, `_`, `-` and `.`.
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
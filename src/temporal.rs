@@ -0,0 +1,97 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use nulls::Null;
+use serde_json::Value;
+
+use crate::{message, Message, Validator};
+
+/// Parses an RFC 3339 timestamp, a `YYYY-MM-DDTHH:MM:SS` local timestamp or a `YYYY-MM-DD` date.
+///
+/// Timestamps with an offset are converted to UTC; dates count as midnight.
+pub(crate) fn parse_instant(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|instant| instant.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|date| date.and_time(NaiveTime::MIN)))
+        .ok()
+}
+
+impl Validator {
+    /// Requires the value to be later than another field's, looked up by name in the context.
+    ///
+    /// # Arguments
+    /// * `field` - The other field, e.g. `created_at`.
+    pub fn set_must_be_after<T>(mut self, field: T) -> Self
+    where T: ToString
+    {
+        self.must_be_after = Some(field.to_string());
+        self
+    }
+
+    /// Requires the value to be earlier than another field's, looked up by name in the context.
+    ///
+    /// # Arguments
+    /// * `field` - The other field, e.g. `expires_at`.
+    pub fn set_must_be_before<T>(mut self, field: T) -> Self
+    where T: ToString
+    {
+        self.must_be_before = Some(field.to_string());
+        self
+    }
+
+    /// Validates that the value is after and/or before the other fields named by `set_must_be_after`
+    /// and `set_must_be_before`, whose values are looked up in the context.
+    ///
+    /// The value is the string value, or the naive date when the string value is empty. Values are
+    /// RFC 3339 timestamps, `YYYY-MM-DDTHH:MM:SS` timestamps or `YYYY-MM-DD` dates. A comparison is
+    /// skipped when the other field is absent or malformed, since that field reports its own error.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, malformed, or not after/before the other field.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_temporal_order(&self) -> Null<String> {
+        message::render(self.check_temporal_order())
+    }
+
+    /// Runs `validate_temporal_order`, returning the unrendered message behind its error.
+    pub(crate) fn check_temporal_order(&self) -> Null<Message> {
+        let instant = match (self.string_value.is_empty(), self.naive_date) {
+            (true, Some(date)) => Some(date.and_time(NaiveTime::MIN)),
+            (true, None) => {
+                return match self.is_required {
+                    true => Null::Value(message::key(format!("{}-empty", self.field))),
+                    false => Null::Undefined
+                };
+            },
+            (false, _) => parse_instant(&self.string_value)
+        };
+
+        let Some(instant) = instant else {
+            return Null::Value(message::key(format!("{}-invalid", self.field)));
+        };
+
+        let other = |field: &str| self.context
+            .get(field)
+            .and_then(Value::as_str)
+            .and_then(parse_instant);
+
+        if let Some(field) = &self.must_be_after
+            && other(field).is_some_and(|other| instant <= other)
+        {
+            return Null::Value(message::new(format!("{}-after", self.field))
+                .set_args("other", field)
+                .into_message());
+        }
+
+        if let Some(field) = &self.must_be_before
+            && other(field).is_some_and(|other| instant >= other)
+        {
+            return Null::Value(message::new(format!("{}-before", self.field))
+                .set_args("other", field)
+                .into_message());
+        }
+
+        Null::Undefined
+    }
+}
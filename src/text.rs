@@ -0,0 +1,194 @@
+use nulls::Null;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{message, Validator};
+
+/// A pluggable text analysis hook for `validate_text`, e.g. backed by a language detection model.
+///
+/// Both methods default to `None`, meaning the analyzer has no opinion and the check passes.
+pub trait TextAnalyzer: Send + Sync {
+    /// Detects the language of the text.
+    ///
+    /// # Returns
+    /// * `Some(String)` - The detected language, as an ISO 639-1 code or BCP 47 tag such as `en` or `en-US`.
+    /// * `None` - If the language could not be detected.
+    fn detect_language(&self, _text: &str) -> Option<String> {
+        None
+    }
+
+    /// Scores how likely the text is gibberish or machine-generated.
+    ///
+    /// # Returns
+    /// * `Some(f64)` - A score from `0.0` (natural text) to `1.0` (gibberish).
+    /// * `None` - If the text could not be scored.
+    fn gibberish_score(&self, _text: &str) -> Option<f64> {
+        None
+    }
+}
+
+impl Validator {
+    /// Sets the maximum number of lines in multi-line text.
+    ///
+    /// # Arguments
+    /// * `max_lines` - The maximum line count.
+    pub fn set_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets the maximum length, in characters, of each line of multi-line text.
+    ///
+    /// # Arguments
+    /// * `max_line_length` - The maximum line length.
+    pub fn set_max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = Some(max_line_length);
+        self
+    }
+
+    /// Sets the minimum number of words in text.
+    ///
+    /// # Arguments
+    /// * `min_words` - The minimum word count.
+    pub fn set_min_words(mut self, min_words: usize) -> Self {
+        self.min_words = Some(min_words);
+        self
+    }
+
+    /// Sets the maximum number of words in text.
+    ///
+    /// # Arguments
+    /// * `max_words` - The maximum word count.
+    pub fn set_max_words(mut self, max_words: usize) -> Self {
+        self.max_words = Some(max_words);
+        self
+    }
+
+    /// Sets the analyzer `validate_text` uses for language and gibberish checks.
+    ///
+    /// # Arguments
+    /// * `text_analyzer` - The analyzer.
+    pub fn set_text_analyzer<T>(mut self, text_analyzer: T) -> Self
+    where T: TextAnalyzer + 'static
+    {
+        self.text_analyzer = Some(Arc::new(text_analyzer));
+        self
+    }
+
+    /// Sets the languages text must be written in, as detected by the text analyzer.
+    ///
+    /// # Arguments
+    /// * `allowed_languages` - ISO 639-1 codes such as `en`; regional tags like `en-US` match their language.
+    pub fn set_allowed_languages<T>(mut self, allowed_languages: &[T]) -> Self
+    where T: ToString
+    {
+        self.allowed_languages = allowed_languages.iter().map(|language| language.to_string()).collect();
+        self
+    }
+
+    /// Sets the highest gibberish score, from the text analyzer, that text may have.
+    ///
+    /// # Arguments
+    /// * `max_gibberish_score` - The maximum score, from `0.0` to `1.0`.
+    pub fn set_max_gibberish_score(mut self, max_gibberish_score: f64) -> Self {
+        self.max_gibberish_score = Some(max_gibberish_score);
+        self
+    }
+
+    /// Validates multi-line text such as bios and descriptions.
+    ///
+    /// Runs `validate_string` first, then the line and word constraints, then the text analyzer's
+    /// language and gibberish checks, which honor `set_timeout`. Per-line errors carry the
+    /// 1-based `line` number of the offending line. Words are counted with Unicode word segmentation,
+    /// so punctuation is ignored and scripts without spaces are segmented properly.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If `validate_string` fails, the text has too many lines, a line is too long,
+    ///   the word count is out of range, or the analyzer rejects the language or flags gibberish.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_text(&self) -> Null<String> {
+        if self.timeout.is_some() && self.text_analyzer.is_some() {
+            return self.validate_with_timeout(Validator::validate_text);
+        }
+
+        let value = self.validate_string();
+        if value.is_some() {
+            return value;
+        }
+
+        let lines = self.string_value.lines().collect::<Vec<&str>>();
+
+        if let Some(max) = self.max_lines
+            && lines.len() > max
+        {
+            return Null::Value(message::new(format!("{}-max-lines", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if let Some(max) = self.max_line_length
+            && let Some(line) = lines.iter().position(|line| line.chars().count() > max)
+        {
+            return Null::Value(message::new(format!("{}-line-max", self.field))
+                .set_args("line", (line + 1).to_string())
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        if self.min_words.is_some() || self.max_words.is_some() {
+            let words = self.string_value.unicode_words().count();
+
+            if let Some(min) = self.min_words
+                && words < min
+            {
+                return Null::Value(message::new(format!("{}-min-words", self.field))
+                    .set_args("min", self.bound(min))
+                    .set_args("count", words.to_string())
+                    .build());
+            }
+
+            if let Some(max) = self.max_words
+                && words > max
+            {
+                return Null::Value(message::new(format!("{}-max-words", self.field))
+                    .set_args("max", self.bound(max))
+                    .set_args("count", words.to_string())
+                    .build());
+            }
+        }
+
+        self.validate_text_analysis()
+    }
+
+    /// Runs the text analyzer's language and gibberish checks, if an analyzer is set.
+    fn validate_text_analysis(&self) -> Null<String> {
+        let Some(analyzer) = &self.text_analyzer else {
+            return Null::Undefined;
+        };
+
+        if self.string_value.is_empty() {
+            return Null::Undefined;
+        }
+
+        if !self.allowed_languages.is_empty()
+            && let Some(language) = analyzer.detect_language(&self.string_value)
+        {
+            let primary = |tag: &str| tag.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+
+            if !self.allowed_languages.iter().any(|allowed| primary(allowed) == primary(&language)) {
+                return Null::Value(message::new(format!("{}-language", self.field))
+                    .set_args("language", language)
+                    .set_args("languages", self.format_options(&self.allowed_languages))
+                    .build());
+            }
+        }
+
+        if let Some(max) = self.max_gibberish_score
+            && analyzer.gibberish_score(&self.string_value).is_some_and(|score| score > max)
+        {
+            return Null::Value(message::get(format!("{}-gibberish", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,179 @@
+use nulls::Null;
+
+use crate::{checksum, message, Error, Valid, Validator};
+
+/// A parcel carrier whose tracking numbers `validate_tracking_number` recognizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Carrier {
+    Ups,
+    FedEx,
+    Usps,
+    Dhl
+}
+
+impl Carrier {
+    /// Returns the carrier's display name, e.g. `UPS`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Carrier::Ups => "UPS",
+            Carrier::FedEx => "FedEx",
+            Carrier::Usps => "USPS",
+            Carrier::Dhl => "DHL"
+        }
+    }
+
+    /// Checks whether a normalized tracking number has this carrier's format.
+    fn is_format(&self, value: &str) -> bool {
+        let is_digits = value.bytes().all(|byte| byte.is_ascii_digit());
+
+        match self {
+            Carrier::Ups => value.len() == 18
+                && value.starts_with("1Z")
+                && value.bytes().all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit()),
+            Carrier::FedEx => is_digits && matches!(value.len(), 12 | 15),
+            Carrier::Usps => (is_digits && matches!(value.len(), 20 | 22) && value.starts_with('9')) || is_s10(value),
+            Carrier::Dhl => is_digits && value.len() == 10
+        }
+    }
+
+    /// Checks a tracking number's check digit, assuming it has this carrier's format.
+    fn is_checksum(&self, value: &str) -> bool {
+        match self {
+            Carrier::Ups => {
+                // Letters map to digits as A=2 … H=9, I=0 …, i.e. (ASCII - 63) mod 10
+                let digits = value[2..]
+                    .bytes()
+                    .map(|byte| match byte.is_ascii_digit() {
+                        true => u32::from(byte - b'0'),
+                        false => (u32::from(byte) - 63) % 10
+                    })
+                    .collect::<Vec<u32>>();
+
+                let sum = digits[..15]
+                    .iter()
+                    .enumerate()
+                    .map(|(index, digit)| digit * if index % 2 == 1 { 2 } else { 1 })
+                    .sum::<u32>();
+
+                (10 - sum % 10) % 10 == digits[15]
+            },
+            Carrier::FedEx => {
+                let Some(digits) = checksum::digits(value) else {
+                    return false;
+                };
+
+                match digits.len() {
+                    // Express: weights 1, 3, 7 from the right, mod 11, mod 10
+                    12 => {
+                        let sum = digits[..11]
+                            .iter()
+                            .rev()
+                            .zip([1, 3, 7].iter().cycle())
+                            .map(|(digit, weight)| u32::from(*digit) * weight)
+                            .sum::<u32>();
+
+                        sum % 11 % 10 == u32::from(digits[11])
+                    },
+                    _ => checksum::gs1(&digits)
+                }
+            },
+            Carrier::Usps if is_s10(value) => {
+                let Some(digits) = checksum::digits(&value[2..11]) else {
+                    return false;
+                };
+
+                let sum = digits[..8]
+                    .iter()
+                    .zip([8, 6, 4, 2, 3, 5, 9, 7])
+                    .map(|(digit, weight)| u32::from(*digit) * weight)
+                    .sum::<u32>();
+
+                let check = match 11 - sum % 11 {
+                    10 => 0,
+                    11 => 5,
+                    check => check
+                };
+
+                check == u32::from(digits[8])
+            },
+            Carrier::Usps => checksum::digits(value).is_some_and(|digits| checksum::gs1(&digits)),
+            Carrier::Dhl => value[..9]
+                .parse::<u64>()
+                .is_ok_and(|number| number % 7 == u64::from(value.as_bytes()[9] - b'0'))
+        }
+    }
+}
+
+/// Checks the UPU S10 format used for international mail, e.g. `EA123456785US`.
+fn is_s10(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    bytes.len() == 13
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..11].iter().all(u8::is_ascii_digit)
+        && bytes[11..].iter().all(u8::is_ascii_uppercase)
+}
+
+impl Validator {
+    /// Restricts tracking numbers to the given carriers.
+    ///
+    /// # Arguments
+    /// * `carriers` - A slice of accepted carriers. Defaults to all carriers.
+    pub fn set_carriers(mut self, carriers: &[Carrier]) -> Self {
+        self.carriers = carriers.to_vec();
+        self
+    }
+
+    /// Validates that the string value is a UPS, FedEx, USPS or DHL tracking number, returning the
+    /// detected carrier.
+    ///
+    /// Spaces and dashes are ignored and letters are uppercased. Check digits are verified for every
+    /// supported format: UPS `1Z` numbers, FedEx Express (12 digits) and Ground (15 digits), USPS
+    /// IMpb (20 or 22 digits) and S10 international numbers, and DHL Express (10 digits).
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Option<Carrier>>)` - The detected carrier if the validation passes successfully; `None` when an optional value is absent.
+    /// * `Err(Error)` - If the field is required but empty, matches no carrier's format, has a wrong
+    ///   check digit, or belongs to a carrier that is not accepted.
+    pub fn validate_tracking_number(&self) -> Result<Valid<Option<Carrier>>, Error> {
+        let (outcome, carrier) = self.detect_carrier();
+        self.to_valid(outcome, carrier)
+    }
+
+    /// Detects the carrier of the tracking number, returning the first failure if there is none.
+    fn detect_carrier(&self) -> (Null<String>, Option<Carrier>) {
+        let value = self.string_value
+            .chars()
+            .filter(|char| !char.is_whitespace() && *char != '-')
+            .collect::<String>()
+            .to_ascii_uppercase();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => (Null::Value(message::get(format!("{}-empty", self.field))), None),
+                false => (Null::Undefined, None)
+            };
+        }
+
+        let candidates = [Carrier::Ups, Carrier::FedEx, Carrier::Usps, Carrier::Dhl]
+            .into_iter()
+            .filter(|carrier| carrier.is_format(&value))
+            .collect::<Vec<Carrier>>();
+
+        if candidates.is_empty() {
+            return (Null::Value(message::get(format!("{}-invalid", self.field))), None);
+        }
+
+        let Some(carrier) = candidates.into_iter().find(|carrier| carrier.is_checksum(&value)) else {
+            return (Null::Value(message::get(format!("{}-checksum", self.field))), None);
+        };
+
+        if !self.carriers.is_empty() && !self.carriers.contains(&carrier) {
+            return (Null::Value(message::new(format!("{}-carrier", self.field))
+                .set_args("carrier", carrier.as_str())
+                .build()), None);
+        }
+
+        (Null::Undefined, Some(carrier))
+    }
+}
@@ -0,0 +1,80 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+impl Validator {
+    /// Sets the allowed state transitions, e.g. `[("draft", "published"), ("published", "archived")]`.
+    ///
+    /// # Arguments
+    /// * `transitions` - A slice of allowed `(from, to)` pairs.
+    pub fn set_transitions<T, U>(mut self, transitions: &[(T, U)]) -> Self
+    where T: ToString, U: ToString
+    {
+        self.transitions = transitions
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect::<Vec<(String, String)>>();
+
+        self
+    }
+
+    /// Validates that moving from the current state to the requested one is an allowed transition.
+    ///
+    /// Staying in the same state is always allowed. States that appear in no transition are reported
+    /// as `{field}-invalid`, and known states without a transition between them as
+    /// `{field}-illegal-transition` with `from` and `to` args.
+    ///
+    /// # Arguments
+    /// * `current` - The state the record is in.
+    /// * `requested` - The state being requested.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but the requested state is empty, a state is unknown, or the transition is not allowed.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_transition<T, U>(&self, current: T, requested: U) -> Null<String>
+    where T: ToString, U: ToString
+    {
+        let (current, requested) = (current.to_string(), requested.to_string());
+
+        if requested.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let matches = |a: &str, b: &str| match self.is_case_sensitive {
+            true => a == b,
+            false => a.to_lowercase() == b.to_lowercase()
+        };
+
+        let is_known = |state: &str| self.transitions
+            .iter()
+            .any(|(from, to)| matches(from, state) || matches(to, state));
+
+        for state in [&current, &requested] {
+            if !is_known(state) {
+                return Null::Value(message::new(format!("{}-invalid", self.field))
+                    .set_args("state", self.list_formatter().quote(state))
+                    .build());
+            }
+        }
+
+        if matches(&current, &requested) {
+            return Null::Undefined;
+        }
+
+        let is_allowed = self.transitions
+            .iter()
+            .any(|(from, to)| matches(from, &current) && matches(to, &requested));
+
+        if !is_allowed {
+            return Null::Value(message::new(format!("{}-illegal-transition", self.field))
+                .set_args("from", self.list_formatter().quote(&current))
+                .set_args("to", self.list_formatter().quote(&requested))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,84 @@
+use nulls::Null;
+
+#[cfg(feature = "travel")]
+use crate::presets;
+use crate::{message, Validator};
+
+/// Characters airlines leave out of booking references because they are easily misread.
+const AMBIGUOUS: &[u8] = b"01IO";
+
+impl Validator {
+    /// Validates that the string value is a booking reference (PNR) such as `K7XQ2M`.
+    ///
+    /// A PNR is 5 or 6 letters and digits, excluding the easily misread `0`, `1`, `I` and `O`.
+    /// Lowercase is accepted unless the validator is case sensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, or if the reference is malformed.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_pnr(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_ascii_uppercase()
+        };
+
+        let is_valid = matches!(value.len(), 5 | 6)
+            && value.bytes().all(|byte| {
+                (byte.is_ascii_uppercase() || byte.is_ascii_digit()) && !AMBIGUOUS.contains(&byte)
+            });
+
+        if !is_valid {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        Null::Undefined
+    }
+
+    /// Validates that the string value is the IATA code of a known airport, such as `LHR`.
+    ///
+    /// Codes are checked against the option list when one is set, and against
+    /// `presets::travel::airports()` otherwise. Lowercase is accepted unless the validator is case sensitive.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not three letters, or not a known airport.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    #[cfg(feature = "travel")]
+    pub fn validate_iata_airport(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if value.len() != 3 || !value.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        let is_known = match (&self.option_list_string, self.is_case_sensitive) {
+            (Some(list), true) => list.iter().any(|code| code == value),
+            (Some(list), false) => list.iter().any(|code| code.eq_ignore_ascii_case(value)),
+            (None, true) => presets::travel::airports().contains(&value),
+            (None, false) => presets::travel::airports().iter().any(|code| code.eq_ignore_ascii_case(value))
+        };
+
+        if !is_known {
+            return Null::Value(message::new(format!("{}-airport", self.field))
+                .set_args("code", self.list_formatter().quote(value))
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
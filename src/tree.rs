@@ -0,0 +1,144 @@
+use nulls::Null;
+use serde_json::{Map, Value};
+
+use crate::{message, Validator, ValidatorSet};
+
+const CHILDREN_KEY: &str = "children";
+
+impl Validator {
+    /// Sets the key under which tree nodes list their children.
+    ///
+    /// # Arguments
+    /// * `children_key` - The key. Defaults to `children`.
+    pub fn set_children_key<T>(mut self, children_key: T) -> Self
+    where T: ToString
+    {
+        self.children_key = Some(children_key.to_string());
+        self
+    }
+
+    /// Sets the maximum number of children of any tree node.
+    ///
+    /// # Arguments
+    /// * `max_children` - The maximum child count.
+    pub fn set_max_children(mut self, max_children: usize) -> Self {
+        self.max_children = Some(max_children);
+        self
+    }
+
+    /// Sets the rules every tree node's own fields must pass.
+    ///
+    /// # Arguments
+    /// * `node_rules` - The node rules, e.g. `form! { "name" => string().required() }`.
+    pub fn set_node_rules(mut self, node_rules: ValidatorSet) -> Self {
+        self.node_rules = Some(node_rules);
+        self
+    }
+
+    /// Validates that the JSON value is a tree of nodes, such as nested comments or categories.
+    ///
+    /// The value is a root node object, or an array of root nodes. Each node is an object whose
+    /// children, if any, are an array under the children key. Nesting is limited by `max_depth`
+    /// (root nodes have a depth of `1`), child counts by `max_children`, and each node's fields
+    /// are checked against the node rules.
+    ///
+    /// Errors are keyed by path, e.g. `children[2].children[0].name`; errors about the value as a
+    /// whole are keyed by the field name. Collection stops after `max_errors` errors.
+    ///
+    /// # Returns
+    /// * `Null::Value` - A map of errors if the tree is required but null, malformed, too deep or wide, or has invalid nodes.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_tree(&self) -> Null<Value> {
+        let mut errors = Map::new();
+
+        if self.json_value.is_null() {
+            if self.is_required {
+                errors.insert(self.field.clone(), message::get(format!("{}-empty", self.field)).into());
+                return Null::Value(Value::Object(errors));
+            }
+
+            return Null::Undefined;
+        }
+
+        let children_key = self.children_key.as_deref().unwrap_or(CHILDREN_KEY);
+        let mut stack = match &self.json_value {
+            Value::Array(roots) => roots
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, node)| (node, format!("[{}]", index), 1))
+                .collect::<Vec<(&Value, String, usize)>>(),
+            node => vec![(node, String::new(), 1)]
+        };
+
+        while let Some((node, path, depth)) = stack.pop() {
+            if self.max_errors.is_some_and(|max| errors.len() >= max) {
+                break;
+            }
+
+            let at = |key: &str| match (path.is_empty(), key.is_empty()) {
+                (true, true) => self.field.clone(),
+                (true, false) => key.to_string(),
+                (false, true) => path.clone(),
+                (false, false) => format!("{}.{}", path, key)
+            };
+
+            let Some(object) = node.as_object() else {
+                errors.insert(at(""), message::get(format!("{}-invalid", self.field)).into());
+                continue;
+            };
+
+            if let Some(rules) = &self.node_rules {
+                for (field, error) in rules.validate(node).errors {
+                    errors.insert(at(&field), error);
+                }
+            }
+
+            let children = match object.get(children_key) {
+                None | Some(Value::Null) => continue,
+                Some(Value::Array(children)) => children,
+                Some(_) => {
+                    errors.insert(at(children_key), message::get(format!("{}-invalid", self.field)).into());
+                    continue;
+                }
+            };
+
+            if children.is_empty() {
+                continue;
+            }
+
+            if let Some(max) = self.max_depth
+                && depth + 1 > max
+            {
+                errors.insert(at(children_key), message::new(format!("{}-max-depth", self.field))
+                    .set_args("max", max)
+                    .build()
+                    .into());
+                continue;
+            }
+
+            if let Some(max) = self.max_children
+                && children.len() > max
+            {
+                errors.insert(at(children_key), message::new(format!("{}-max-children", self.field))
+                    .set_args("max", max)
+                    .set_args("count", children.len())
+                    .build()
+                    .into());
+                continue;
+            }
+
+            stack.extend(children
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, child)| (child, format!("{}[{}]", at(children_key), index), depth + 1)));
+        }
+
+        if !errors.is_empty() {
+            return Null::Value(Value::Object(errors));
+        }
+
+        Null::Undefined
+    }
+}
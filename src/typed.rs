@@ -0,0 +1,128 @@
+use std::fmt;
+use std::ops::Deref;
+
+use nulls::Null;
+
+#[cfg(feature = "email")]
+use crate::Email;
+use crate::Validator;
+
+/// A value that has passed validation.
+///
+/// `Valid<T>` can only be produced by this crate's `*_typed` validators, so functions taking a
+/// `Valid<T>` are guaranteed at compile time to receive already-validated input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Valid<T>(T);
+
+impl<T> Valid<T> {
+    /// Wraps a value that has passed validation.
+    pub(crate) fn new(value: T) -> Self {
+        Valid(value)
+    }
+
+    /// Returns a reference to the validated value.
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwraps the validated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Valid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A failed validation, carrying the field name and its localized message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub field: String,
+    pub message: String
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Validator {
+    /// Converts a validation outcome into a typed result.
+    ///
+    /// # Arguments
+    /// * `outcome` - The outcome of one of the `validate_*` methods.
+    /// * `value` - The value to wrap when the outcome is a success.
+    pub(crate) fn to_valid<T>(&self, outcome: Null<String>, value: T) -> Result<Valid<T>, Error> {
+        match outcome {
+            Null::Value(message) => Err(Error { field: self.field.clone(), message }),
+            _ => Ok(Valid::new(value))
+        }
+    }
+
+    /// Validates the string value like `validate_string`, returning it as a typed value.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<String>)` - If the validation passes successfully.
+    /// * `Err(Error)` - If the string is empty or violates the minimum/maximum length constraints.
+    pub fn validate_string_typed(&self) -> Result<Valid<String>, Error> {
+        self.to_valid(self.validate_string(), self.string_value.clone())
+    }
+
+    /// Validates the string value like `validate_name`, returning it as a typed value.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<String>)` - If the validation passes successfully.
+    /// * `Err(Error)` - If the value is empty, violates the length constraints, or is not a valid name.
+    #[cfg(feature = "regex")]
+    pub fn validate_name_typed(&self) -> Result<Valid<String>, Error> {
+        self.to_valid(self.validate_name(), self.string_value.clone())
+    }
+
+    /// Validates the string value like `validate_email`, returning it as a typed `Email`.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Email>)` - If the validation passes successfully.
+    /// * `Err(Error)` - If the field is empty or the email format is invalid.
+    #[cfg(feature = "email")]
+    pub fn validate_email_typed(&self) -> Result<Valid<Email>, Error> {
+        self.to_valid(self.validate_email(), Email::new(self.string_value.clone()))
+    }
+
+    /// Validates the `i64` value like `validate_i64`, returning it as a typed value.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Option<i64>>)` - If the validation passes successfully; `None` when an optional value is absent.
+    /// * `Err(Error)` - If the field is required but empty, or if it violates the minimum/maximum constraints.
+    pub fn validate_i64_typed(&self) -> Result<Valid<Option<i64>>, Error> {
+        self.to_valid(self.validate_i64(), self.i64_value)
+    }
+
+    /// Validates the `f64` value like `validate_f64`, returning it as a typed value.
+    ///
+    /// # Returns
+    /// * `Ok(Valid<Option<f64>>)` - If the validation passes successfully; `None` when an optional value is absent.
+    /// * `Err(Error)` - If the field is required but empty, or if it violates the minimum/maximum constraints.
+    pub fn validate_f64_typed(&self) -> Result<Valid<Option<f64>>, Error> {
+        self.to_valid(self.validate_f64(), self.f64_value)
+    }
+}
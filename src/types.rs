@@ -0,0 +1,171 @@
+use std::fmt;
+
+use nulls::Null;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(not(feature = "phone"))]
+use crate::message;
+use crate::{Error, Validator};
+
+/// Implements the accessors and conversions shared by every validated newtype.
+macro_rules! newtype {
+    ($name:ident) => {
+        impl $name {
+            /// Returns the value as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Unwraps the underlying string.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.0)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Self::try_from(value.as_str()).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// An email address that has passed `validate_email`.
+#[cfg(feature = "email")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Email(String);
+
+/// A phone number in E.164 form, e.g. `+639171234567`, with any extension appended as `;ext=1234`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PhoneNumber(String);
+
+/// A 3 to 32 character handle made of lowercase letters, digits, `_`, `.` and `-`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Username(String);
+
+/// A URL slug made of lowercase letters, digits and single inner hyphens, e.g. `hello-world`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Slug(String);
+
+#[cfg(feature = "email")]
+newtype!(Email);
+newtype!(PhoneNumber);
+newtype!(Username);
+newtype!(Slug);
+
+#[cfg(feature = "email")]
+impl Email {
+    /// Wraps an already-validated email address.
+    pub(crate) fn new(value: String) -> Self {
+        Email(value)
+    }
+}
+
+#[cfg(feature = "phone")]
+impl PhoneNumber {
+    /// Wraps an already-validated, normalized phone number.
+    pub(crate) fn new(value: String) -> Self {
+        PhoneNumber(value)
+    }
+}
+
+#[cfg(feature = "email")]
+impl TryFrom<&str> for Email {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Validator::new("email")
+            .set_string_value(&Null::Value(value.to_string()))
+            .validate_email_typed()
+            .map(|email| email.into_inner())
+    }
+}
+
+#[cfg(feature = "phone")]
+impl TryFrom<&str> for PhoneNumber {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Validator::new("phone")
+            .set_string_value(&Null::Value(value.to_string()))
+            .set_as_required(true)
+            .validate_phone_typed()
+            .map(|phone| phone.into_inner())
+    }
+}
+
+/// Without the `phone` feature, only the E.164 shape is checked: a `+` and 8 to 15 digits.
+#[cfg(not(feature = "phone"))]
+impl TryFrom<&str> for PhoneNumber {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let validator = Validator::new("phone").set_string_value(&Null::Value(value.to_string()));
+        let digits = value.strip_prefix('+').unwrap_or_default();
+        let outcome = match () {
+            _ if value.is_empty() => Null::Value(message::get("phone-empty")),
+            _ if !(8..=15).contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) => {
+                Null::Value(message::get("phone-invalid"))
+            },
+            _ => Null::Undefined
+        };
+
+        validator.to_valid(outcome, PhoneNumber(value.to_string())).map(|phone| phone.into_inner())
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let validator = Validator::new("username")
+            .set_string_value(&Null::Value(value.to_string()))
+            .set_as_required(true)
+            .set_as_case_sensitive(true);
+
+        let outcome = validator.validate_username();
+
+        validator.to_valid(outcome, Username(value.trim().to_string())).map(|username| username.into_inner())
+    }
+}
+
+impl TryFrom<&str> for Slug {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let validator = Validator::new("slug")
+            .set_string_value(&Null::Value(value.to_string()))
+            .set_as_required(true);
+
+        let outcome = validator.validate_slug();
+
+        validator.to_valid(outcome, Slug(value.to_string())).map(|slug| slug.into_inner())
+    }
+}
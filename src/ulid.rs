@@ -0,0 +1,53 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Decodes a Crockford base32 digit, case-insensitively; `I`, `L`, `O` and `U` are not digits.
+fn crockford_digit(byte: u8) -> Option<u64> {
+    CROCKFORD
+        .iter()
+        .position(|digit| *digit == byte.to_ascii_uppercase())
+        .map(|position| position as u64)
+}
+
+impl Validator {
+    /// Validates that the string value is a ULID: 26 Crockford base32 characters whose embedded
+    /// timestamp is not in the future.
+    ///
+    /// When a maximum skew is set, the timestamp may run that far ahead of the clock, to tolerate ULIDs
+    /// minted on hosts with a fast clock.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a ULID, or timestamped in the future.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_ulid(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let digits = value.bytes().map(crockford_digit).collect::<Option<Vec<u64>>>();
+
+        // The first digit only carries 3 bits, since the 128-bit value spans 130 bits of base32
+        let Some(digits) = digits.filter(|digits| digits.len() == 26 && digits[0] <= 7) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        let timestamp = digits[..10].iter().fold(0, |timestamp, digit| timestamp << 5 | digit);
+        let skew = self.max_skew.map_or(0, |skew| skew.as_millis() as u64);
+        // The clock has whole seconds, so anything within the current second counts as now
+        let now = u64::try_from(self.now()).unwrap_or_default().saturating_mul(1000).saturating_add(999);
+
+        if timestamp > now.saturating_add(skew) {
+            return Null::Value(message::get(format!("{}-future", self.field)));
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,213 @@
+use nulls::Null;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::hostname::is_hostname;
+use crate::{message, Validator};
+
+/// The parts of an absolute URL that validators inspect.
+pub(crate) struct Url {
+    pub scheme: String,
+    pub host: String
+}
+
+/// Returns the IPv4 address embedded in an IPv4-mapped (`::ffff:a.b.c.d`), NAT64 (`64:ff9b::a.b.c.d`)
+/// or IPv4-compatible (`::a.b.c.d`) IPv6 address.
+fn embedded_ipv4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    let [a, b, c, d, e, f, ..] = ip.segments();
+    let [.., w, x, y, z] = ip.octets();
+
+    match (a, b, c, d, e, f) {
+        (0, 0, 0, 0, 0, 0xffff) | (0x64, 0xff9b, 0, 0, 0, 0) | (0, 0, 0, 0, 0, 0) => Some(Ipv4Addr::new(w, x, y, z)),
+        _ => None
+    }
+}
+
+/// Checks whether an IP address is loopback, private, link-local, unspecified, multicast, reserved or
+/// otherwise not publicly routable. IPv6 addresses embedding an IPv4 address are checked as IPv4.
+fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, ..] = ip.octets();
+
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || a == 0
+                || a >= 240
+                || (a == 100 && (64..128).contains(&b))
+                || (a == 192 && b == 0 && c == 0)
+                || (a == 198 && (18..20).contains(&b))
+        },
+        IpAddr::V6(ip) => match embedded_ipv4(ip) {
+            Some(ip) => is_private_ip(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80
+                    || (first & 0xffc0) == 0xfec0
+            }
+        }
+    }
+}
+
+/// Checks whether a lowercase URL host points at the local machine, a private network or a cloud
+/// metadata service.
+///
+/// Only the literal host is inspected; a public name resolving to a private address must be caught
+/// when connecting. Hosts ending in a numeric label that is not a dotted-quad IPv4 address, such as
+/// `2130706433` or `0x7f.1`, are treated as private because many clients resolve them to IPs.
+pub(crate) fn is_private_host(host: &str) -> bool {
+    let host = host.strip_suffix('.').unwrap_or(host);
+
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_private_ip(ip);
+    }
+
+    let last = host.rsplit('.').next().unwrap_or_default();
+
+    host == "localhost"
+        || host.ends_with(".localhost")
+        || host.ends_with(".internal")
+        || host == "metadata"
+        || last.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Parses an absolute URL of the form `scheme://[userinfo@]host[:port][/path][?query][#fragment]`.
+///
+/// Hosts are DNS names, IPv4 addresses or bracketed IPv6 addresses. Whitespace and control characters
+/// are rejected anywhere.
+pub(crate) fn parse_url(value: &str) -> Option<Url> {
+    if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return None;
+    }
+
+    let (scheme, rest) = value.split_once("://")?;
+    let mut chars = scheme.chars();
+
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        || !chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    {
+        return None;
+    }
+
+    // WHATWG parsers end the authority at `\` too, so `http://127.0.0.1\@evil.com` connects to 127.0.0.1
+    let authority = rest.split(['/', '\\', '?', '#']).next().unwrap_or_default();
+    let authority = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, rest) = bracketed.split_once(']')?;
+            host.parse::<Ipv6Addr>().ok()?;
+
+            match rest {
+                "" => (host, None),
+                rest => (host, Some(rest.strip_prefix(':')?))
+            }
+        },
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None)
+        }
+    };
+
+    if let Some(port) = port
+        && (port.is_empty() || !port.bytes().all(|byte| byte.is_ascii_digit()) || port.parse::<u16>().is_err())
+    {
+        return None;
+    }
+
+    if !authority.starts_with('[') && host.parse::<Ipv4Addr>().is_err() && !is_hostname(host) {
+        return None;
+    }
+
+    Some(Url { scheme: scheme.to_ascii_lowercase(), host: host.to_ascii_lowercase() })
+}
+
+impl Validator {
+    /// Sets the schemes URLs may use, compared case-insensitively.
+    ///
+    /// # Arguments
+    /// * `allowed_schemes` - A slice of schemes such as `https`.
+    pub fn set_allowed_schemes<T>(mut self, allowed_schemes: &[T]) -> Self
+    where T: ToString
+    {
+        self.allowed_schemes = allowed_schemes.iter().map(|scheme| scheme.to_string().to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// Configures whether URLs pointing at the local machine, private networks, link-local addresses or
+    /// cloud metadata services are rejected, as a guard against server-side request forgery.
+    ///
+    /// Only the literal host is checked, so requests must still be made through a client that refuses
+    /// private addresses after DNS resolution.
+    ///
+    /// # Arguments
+    /// * `is_private_host_rejected` - A boolean indicating if private hosts are rejected.
+    pub fn set_reject_private_hosts(mut self, is_private_host_rejected: bool) -> Self {
+        self.is_private_host_rejected = is_private_host_rejected;
+        self
+    }
+
+    /// Validates that the string value is an absolute URL with a scheme and host, and an optional port,
+    /// path, query and fragment.
+    ///
+    /// The length is checked against the minimum/maximum constraints, and the scheme against the allowed
+    /// schemes when any are set. Private hosts are rejected when `set_reject_private_hosts` is enabled.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, violates the length constraints, is not an
+    ///   absolute URL, uses a scheme that is not allowed, or points at a rejected private host.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_url(&self) -> Null<String> {
+        if self.string_value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let value = self.validate_string_length();
+        if value.is_some() {
+            return value;
+        }
+
+        let Some(url) = parse_url(&self.string_value) else {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        };
+
+        if !self.allowed_schemes.is_empty() && !self.allowed_schemes.contains(&url.scheme) {
+            return Null::Value(message::new(format!("{}-scheme", self.field))
+                .set_args("scheme", url.scheme)
+                .set_args("options", self.format_options(&self.allowed_schemes))
+                .build());
+        }
+
+        if self.is_private_host_rejected && is_private_host(&url.host) {
+            return Null::Value(message::new(format!("{}-private-host", self.field))
+                .set_args("host", url.host)
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backslash_ends_the_authority() {
+        let url = parse_url("http://127.0.0.1\\@evil.com/").unwrap();
+
+        assert_eq!(url.host, "127.0.0.1");
+        assert!(is_private_host(&url.host));
+    }
+}
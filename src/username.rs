@@ -0,0 +1,110 @@
+use nulls::Null;
+
+use crate::chars::{in_class, parse_class};
+#[cfg(feature = "confusables")]
+use crate::confusable_skeleton;
+use crate::{message, Validator};
+
+const USERNAME_MIN: usize = 3;
+const USERNAME_MAX: usize = 32;
+const USERNAME_CHARS: &str = "a-z0-9_.-";
+
+/// Reduces a handle to the form it is compared with reserved names in, dropping the `_`, `.` and `-`
+/// separators so that `ad.min` and `ad_min` read as `admin`.
+fn fold_handle(value: &str, is_case_sensitive: bool) -> String {
+    let value = value.chars().filter(|c| !matches!(c, '_' | '.' | '-')).collect::<String>();
+
+    match is_case_sensitive {
+        true => value,
+        false => value.to_lowercase()
+    }
+}
+
+impl Validator {
+    /// Sets the handles that cannot be registered, such as `admin`, `root` or `support`.
+    ///
+    /// # Arguments
+    /// * `reserved_list` - A slice of reserved handles.
+    pub fn set_reserved_list<T>(mut self, reserved_list: &[T]) -> Self
+    where T: ToString
+    {
+        self.reserved_list = reserved_list.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Validates that the string value is a username within the length bounds, made of the allowed
+    /// characters and not imitating a reserved handle.
+    ///
+    /// Lengths default to 3 through 32 characters and allowed characters to `a-z0-9_.-`. Unless the
+    /// validator is case sensitive, the value is lowercased before the character check, so `JaneDoe` is
+    /// accepted as `janedoe`. Reserved handles are compared ignoring `_`, `.` and `-` separators, and
+    /// with the `confusables` feature also by their `confusable_skeleton`; the matching handle is
+    /// reported in the `name` arg.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, too short or long, has a character outside
+    ///   the allowed class, or imitates a reserved handle.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_username(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        let min = self.min.unwrap_or(USERNAME_MIN);
+        let max = self.max.unwrap_or(USERNAME_MAX);
+        let len = value.chars().count();
+
+        if len < min {
+            return Null::Value(message::new(format!("{}-min", self.field))
+                .set_args("min", self.bound(min))
+                .build());
+        }
+
+        if len > max {
+            return Null::Value(message::new(format!("{}-max", self.field))
+                .set_args("max", self.bound(max))
+                .build());
+        }
+
+        let allowed = parse_class(self.allowed_chars.as_deref().unwrap_or(USERNAME_CHARS));
+        let folded = match self.is_case_sensitive {
+            true => value.to_string(),
+            false => value.to_lowercase()
+        };
+
+        if let Some(c) = folded.chars().find(|c| !in_class(*c, &allowed)) {
+            return Null::Value(message::new(format!("{}-invalid-char", self.field))
+                .set_args("char", c.to_string())
+                .build());
+        }
+
+        let handle = fold_handle(value, self.is_case_sensitive);
+
+        #[cfg(feature = "confusables")]
+        let skeleton = confusable_skeleton(&handle);
+
+        let reserved = self.reserved_list.iter().find(|name| {
+            let name = fold_handle(name, self.is_case_sensitive);
+
+            #[cfg(feature = "confusables")]
+            if confusable_skeleton(&name) == skeleton {
+                return true;
+            }
+
+            name == handle
+        });
+
+        if let Some(name) = reserved {
+            return Null::Value(message::new(format!("{}-reserved", self.field))
+                .set_args("name", name.as_str())
+                .build());
+        }
+
+        Null::Undefined
+    }
+}
@@ -0,0 +1,59 @@
+use nulls::Null;
+
+use crate::{message, Validator};
+
+/// Checks for the canonical hyphenated UUID form, e.g. `123e4567-e89b-12d3-a456-426614174000`.
+pub(crate) fn is_uuid(value: &str) -> bool {
+    value.len() == 36 && value.char_indices().all(|(i, c)| match i {
+        8 | 13 | 18 | 23 => c == '-',
+        _ => c.is_ascii_hexdigit()
+    })
+}
+
+impl Validator {
+    /// Sets the UUID version, such as 4 or 7, that UUIDs must have.
+    ///
+    /// # Arguments
+    /// * `uuid_version` - The version, from 1 to 8.
+    pub fn set_uuid_version(mut self, uuid_version: u8) -> Self {
+        self.uuid_version = Some(uuid_version);
+        self
+    }
+
+    /// Validates that the string value is a UUID in the canonical hyphenated form, in either case.
+    ///
+    /// When a UUID version is set, the version digit must match and the variant must be the RFC 9562
+    /// one. The nil and max UUIDs have no version, so they only pass without a version constraint.
+    ///
+    /// # Returns
+    /// * `Null::Value` - If the field is required but empty, not a UUID, or not of the required version.
+    /// * `Null::Undefined` - If the validation passes successfully.
+    pub fn validate_uuid(&self) -> Null<String> {
+        let value = self.string_value.trim();
+
+        if value.is_empty() {
+            return match self.is_required {
+                true => Null::Value(message::get(format!("{}-empty", self.field))),
+                false => Null::Undefined
+            };
+        }
+
+        if !is_uuid(value) {
+            return Null::Value(message::get(format!("{}-invalid", self.field)));
+        }
+
+        if let Some(version) = self.uuid_version {
+            let bytes = value.as_bytes();
+            let is_version = char::from(bytes[14]).to_digit(16) == Some(u32::from(version));
+            let is_variant = matches!(bytes[19], b'8' | b'9' | b'a' | b'b' | b'A' | b'B');
+
+            if !is_version || !is_variant {
+                return Null::Value(message::new(format!("{}-version", self.field))
+                    .set_args("version", version)
+                    .build());
+            }
+        }
+
+        Null::Undefined
+    }
+}